@@ -0,0 +1,300 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    Expr, Ident, LitChar, LitInt, Token, braced,
+    parse::{Parse, ParseStream},
+};
+
+pub(crate) struct Keys {
+    event: Expr,
+    arms: Vec<Arm>,
+    fallback: Option<Expr>,
+}
+
+struct Arm {
+    chords: Vec<Chord>,
+    body: Expr,
+}
+
+struct Chord {
+    code: TokenStream,
+    modifiers: Vec<Ident>,
+    // Shift is part of the key itself for characters (`A`) and back tab.
+    implied_shift: bool,
+}
+
+impl Parse for Keys {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let event = input.parse()?;
+        input.parse::<Token![,]>()?;
+
+        let content;
+        braced!(content in input);
+
+        let mut arms = Vec::new();
+        let mut fallback = None;
+
+        while !content.is_empty() {
+            let wildcard = content.peek(Token![_]);
+            let chords = if wildcard {
+                let underscore = content.parse::<Token![_]>()?;
+                if fallback.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        underscore,
+                        "only one `_` arm is allowed",
+                    ));
+                }
+                Vec::new()
+            } else {
+                let mut chords = vec![content.parse()?];
+                while content.parse::<Option<Token![|]>>()?.is_some() {
+                    chords.push(content.parse()?);
+                }
+                chords
+            };
+
+            content.parse::<Token![=>]>()?;
+            let body: Expr = content.parse()?;
+
+            let block_like = matches!(
+                body,
+                Expr::Block(_)
+                    | Expr::If(_)
+                    | Expr::Match(_)
+                    | Expr::Loop(_)
+                    | Expr::While(_)
+                    | Expr::ForLoop(_)
+                    | Expr::Unsafe(_)
+            );
+            if content.parse::<Option<Token![,]>>()?.is_none() && !block_like && !content.is_empty()
+            {
+                return Err(content.error("expected `,` after a key arm"));
+            }
+
+            if wildcard {
+                fallback = Some(body);
+            } else {
+                arms.push(Arm { chords, body });
+            }
+        }
+
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Keys {
+            event,
+            arms,
+            fallback,
+        })
+    }
+}
+
+impl Parse for Chord {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut modifiers: Vec<Ident> = Vec::new();
+
+        loop {
+            let key = Key::parse(input)?;
+            if input.parse::<Option<Token![+]>>()?.is_none() {
+                return key.into_chord(modifiers);
+            }
+
+            match key {
+                Key::Named(ident) if modifier(&ident).is_some() => {
+                    let flag = Ident::new(modifier(&ident).unwrap_or_default(), ident.span());
+                    if modifiers.contains(&flag) {
+                        return Err(syn::Error::new_spanned(ident, "duplicate modifier"));
+                    }
+                    modifiers.push(flag);
+                }
+                key => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "expected a modifier: `ctrl`, `alt`, `shift` or `super`",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+enum Key {
+    Named(Ident),
+    Char(LitChar),
+    Digit(LitInt),
+}
+
+impl Key {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(LitChar) {
+            input.parse().map(Key::Char)
+        } else if lookahead.peek(LitInt) {
+            input.parse().map(Key::Digit)
+        } else if lookahead.peek(Token![super]) {
+            let token = input.parse::<Token![super]>()?;
+            Ok(Key::Named(Ident::new("super", token.span)))
+        } else if lookahead.peek(Ident) {
+            input.parse().map(Key::Named)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Key::Named(ident) => ident.span(),
+            Key::Char(lit) => lit.span(),
+            Key::Digit(lit) => lit.span(),
+        }
+    }
+
+    fn into_chord(self, mut modifiers: Vec<Ident>) -> syn::Result<Chord> {
+        let shift = modifiers.iter().position(|flag| flag == "SHIFT");
+
+        let (code, implied_shift) = match self {
+            Key::Char(lit) => (char_code(shifted(lit.value(), shift.is_some())), true),
+            Key::Digit(lit) => {
+                let digit = lit.base10_parse::<u8>()?;
+                if digit > 9 {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "expected a single digit, use a char literal for other keys",
+                    ));
+                }
+                (char_code(char::from(b'0' + digit)), true)
+            }
+            Key::Named(ident) => {
+                let name = ident.to_string();
+                let mut chars = name.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if c.is_ascii_alphanumeric() => {
+                        (char_code(shifted(c, shift.is_some())), true)
+                    }
+                    _ if name == "tab" && shift.is_some() => (named_code("BackTab"), true),
+                    _ => match named_key(&name) {
+                        Some(code) => (code, false),
+                        None => {
+                            let message = match modifier(&ident) {
+                                Some(_) => format!(
+                                    "`{name}` is a modifier and needs a key, e.g. `{name}+c`"
+                                ),
+                                None => format!("unknown key `{name}`"),
+                            };
+                            return Err(syn::Error::new_spanned(ident, message));
+                        }
+                    },
+                }
+            }
+        };
+
+        if implied_shift && let Some(index) = shift {
+            modifiers.remove(index);
+        }
+
+        Ok(Chord {
+            code,
+            modifiers,
+            implied_shift,
+        })
+    }
+}
+
+fn modifier(ident: &Ident) -> Option<&'static str> {
+    match ident.to_string().as_str() {
+        "ctrl" => Some("CONTROL"),
+        "alt" => Some("ALT"),
+        "shift" => Some("SHIFT"),
+        "super" => Some("SUPER"),
+        _ => None,
+    }
+}
+
+fn shifted(c: char, shift: bool) -> char {
+    if shift { c.to_ascii_uppercase() } else { c }
+}
+
+fn char_code(c: char) -> TokenStream {
+    quote!(ratatui::crossterm::event::KeyCode::Char(#c))
+}
+
+fn named_code(name: &str) -> TokenStream {
+    let ident = Ident::new(name, Span::call_site());
+    quote!(ratatui::crossterm::event::KeyCode::#ident)
+}
+
+fn named_key(name: &str) -> Option<TokenStream> {
+    let code = match name {
+        "space" => return Some(char_code(' ')),
+        "up" => "Up",
+        "down" => "Down",
+        "left" => "Left",
+        "right" => "Right",
+        "enter" => "Enter",
+        "esc" => "Esc",
+        "tab" => "Tab",
+        "backtab" => "BackTab",
+        "backspace" => "Backspace",
+        "delete" => "Delete",
+        "insert" => "Insert",
+        "home" => "Home",
+        "end" => "End",
+        "pageup" => "PageUp",
+        "pagedown" => "PageDown",
+        _ => {
+            let number = name.strip_prefix('f')?.parse::<u8>().ok()?;
+            return (1..=24)
+                .contains(&number)
+                .then(|| quote!(ratatui::crossterm::event::KeyCode::F(#number)));
+        }
+    };
+
+    Some(named_code(code))
+}
+
+pub(crate) fn keys(keys: Keys) -> TokenStream {
+    let Keys {
+        event,
+        arms,
+        fallback,
+    } = keys;
+
+    // Mixed-site so the binding can't shadow or be shadowed by the caller's variables.
+    let key = Ident::new("key", Span::mixed_site());
+
+    let branches = arms.iter().map(|Arm { chords, body }| {
+        let conditions = chords.iter().map(|chord| {
+            let code = &chord.code;
+            let modifiers = match chord.modifiers.as_slice() {
+                [] => quote!(ratatui::crossterm::event::KeyModifiers::NONE),
+                flags => quote!(#(ratatui::crossterm::event::KeyModifiers::#flags)|*),
+            };
+            let pressed = if chord.implied_shift {
+                quote!(#key.modifiers.difference(ratatui::crossterm::event::KeyModifiers::SHIFT))
+            } else {
+                quote!(#key.modifiers)
+            };
+
+            quote!((#key.code == #code && #pressed == #modifiers))
+        });
+
+        quote! {
+            if #(#conditions)||* {
+                #body;
+            }
+        }
+    });
+
+    let fallback = fallback.map(|body| quote!(#body;));
+
+    quote! {
+        if let ratatui::crossterm::event::Event::Key(#key) = &(#event) {
+            if #key.kind == ratatui::crossterm::event::KeyEventKind::Press {
+                #(#branches else)* {
+                    #fallback
+                }
+            }
+        }
+    }
+}
@@ -1,138 +1,777 @@
 use proc_macro::TokenStream;
-use proc_macro2::Ident;
-use quote::quote;
-use syn::{Data, DataEnum, DeriveInput, Type, parse_macro_input};
+use proc_macro2::{Ident, TokenTree};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    Data, DataEnum, DeriveInput, Fields, GenericParam, Generics, ItemImpl, LitStr, Path, Type,
+    Visibility, parse_macro_input, parse_quote, spanned::Spanned,
+};
 
-#[proc_macro_derive(Pages)]
+mod keys;
+mod page_impl;
+
+#[proc_macro_derive(Pages, attributes(page, pages, default))]
 pub fn page(input: proc_macro::TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    match pages_derive(&input) {
-        Ok(tokens) => tokens,
-        Err(tokens) => tokens,
+    pages_derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro]
+pub fn keys(input: TokenStream) -> TokenStream {
+    let keys = parse_macro_input!(input as keys::Keys);
+
+    keys::keys(keys).into()
+}
+
+#[proc_macro_attribute]
+pub fn page_impl(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as page_impl::PageImplArgs);
+    let item = parse_macro_input!(item as ItemImpl);
+
+    page_impl::page_impl(args, item).into()
+}
+
+#[derive(Default)]
+struct Errors(Option<syn::Error>);
+
+impl Errors {
+    fn push(&mut self, err: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(err),
+            None => self.0 = Some(err),
+        }
+    }
+
+    fn check<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        result.map_err(|err| self.push(err)).ok()
+    }
+
+    fn finish(self) -> syn::Result<()> {
+        self.0.map_or(Ok(()), Err)
     }
 }
 
-fn pages_derive(input: &DeriveInput) -> Result<TokenStream, TokenStream> {
+fn pages_derive(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let r#enum = get_enum(input)?;
-    let variants = get_pages_variants(r#enum)?;
 
-    let page_id_tokens = generate_page_id(&variants);
-    let page_state_impl = generate_page_state_impl(&input.ident, &variants);
+    let mut errors = Errors::default();
+    let options = errors.check(get_pages_options(input));
+    let variants = errors.check(get_pages_variants(r#enum));
+    if let Some(variants) = &variants {
+        errors.check(check_inline_fields(input, variants));
+    }
+    let default_impl = variants
+        .as_ref()
+        .and_then(|variants| errors.check(generate_default_impl(input, variants)));
+    errors.finish()?;
+
+    let (Some(options), Some(variants), Some(default_impl)) = (options, variants, default_impl)
+    else {
+        unreachable!("errors are reported above");
+    };
+
+    let page_id_tokens = generate_page_id(&options, &variants);
+    let page_state_impl = generate_page_state_impl(input, &options, &variants);
 
     Ok(quote! {
         #page_id_tokens
 
         #page_state_impl
-    }
-    .into())
+
+        #default_impl
+    })
 }
 
-fn get_enum(input: &DeriveInput) -> Result<&syn::DataEnum, proc_macro::TokenStream> {
+fn get_enum(input: &DeriveInput) -> syn::Result<&DataEnum> {
     match &input.data {
         Data::Enum(data_enum) => Ok(data_enum),
-        _ => Err(quote! {
-            compile_error!("#[derive(ratatui_recipe::pages)] can only be used on enums. Check out the ratapp documentation for more information.");
+        Data::Struct(data) => Err(syn::Error::new_spanned(
+            data.struct_token,
+            "#[derive(Pages)] can only be used on enums",
+        )),
+        Data::Union(data) => Err(syn::Error::new_spanned(
+            data.union_token,
+            "#[derive(Pages)] can only be used on enums",
+        )),
+    }
+}
+
+// The page ID isn't generic, so it can't hold fields that depend on the enum's generics.
+fn check_inline_fields(input: &DeriveInput, variants: &[PageVariant]) -> syn::Result<()> {
+    let mut errors = Errors::default();
+
+    for variant in variants {
+        if let PageKind::Inline { .. } = variant.kind {
+            for field in variant.fields {
+                if mentions_generics(&field.ty, &input.generics) {
+                    errors.push(syn::Error::new_spanned(
+                        &field.ty,
+                        "inline page fields can't use the enum's generic parameters",
+                    ));
+                }
+            }
         }
-        .into()),
     }
+
+    errors.finish()
 }
 
-fn get_pages_variants(input: &DataEnum) -> Result<Vec<(&Ident, &Type)>, proc_macro::TokenStream> {
+struct PagesOptions {
+    id: Ident,
+    vis: Visibility,
+    derives: Vec<Path>,
+    state: Option<Type>,
+}
+
+fn get_pages_options(input: &DeriveInput) -> syn::Result<PagesOptions> {
+    let mut errors = Errors::default();
+    let mut options = PagesOptions {
+        id: Ident::new(&format!("{}ID", input.ident), input.ident.span()),
+        vis: input.vis.clone(),
+        derives: Vec::new(),
+        state: None,
+    };
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("pages"))
+    {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                let value: LitStr = meta.value()?.parse()?;
+                options.id = value.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("vis") {
+                let value: LitStr = meta.value()?.parse()?;
+                options.vis = value.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|derive| {
+                    options.derives.push(derive.path);
+                    Ok(())
+                })
+            } else if meta.path.is_ident("state") {
+                options.state = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown #[pages] option"))
+            }
+        });
+        errors.check(result);
+    }
+
+    errors.finish().map(|()| options)
+}
+
+enum PageKind<'a> {
+    Wrapped(&'a Type),
+    Inline {
+        fields: Vec<&'a Ident>,
+        render: Path,
+        on_event: Option<Path>,
+    },
+}
+
+struct PageVariant<'a> {
+    name: &'a Ident,
+    fields: &'a Fields,
+    kind: PageKind<'a>,
+    keep_alive: bool,
+    home: bool,
+    launch_mode: Option<Ident>,
+    transition: Option<Ident>,
+    title: Option<LitStr>,
+    icon: Option<LitStr>,
+    hidden: bool,
+    overrides: Vec<(Ident, Path)>,
+}
+
+const LIFECYCLE_HOOKS: &[&str] = &["on_enter", "on_exit", "on_pause", "on_resume"];
+
+fn get_pages_variants(input: &DataEnum) -> syn::Result<Vec<PageVariant<'_>>> {
+    let mut errors = Errors::default();
     let mut result = Vec::new();
 
     for variant in &input.variants {
         let name = &variant.ident;
-        let ty = match &variant.fields {
-            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
-            _ => {
-                return Err(quote! {
-                    compile_error!("#[derive(ratatui_recipe::pages)] can only be used on enums with single unnamed field variants (i.e. `Variant(YourpageType)`). Check out the ratapp documentation for more information.");
-                }.into());
+        let mut keep_alive = false;
+        let mut home = false;
+        let mut launch_mode = None;
+        let mut transition = None;
+        let mut title = None;
+        let mut icon = None;
+        let mut hidden = false;
+        let mut render = None;
+        let mut on_event = None;
+        let mut overrides: Vec<(Ident, Path)> = Vec::new();
+
+        for attr in &variant.attrs {
+            if attr.path().is_ident("default") {
+                if errors.check(attr.meta.require_path_only()).is_some() {
+                    home = true;
+                }
+            } else if attr.path().is_ident("pages") {
+                let result = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("home") {
+                        home = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unknown #[pages] option on a variant"))
+                    }
+                });
+                errors.check(result);
+            }
+        }
+
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("page"))
+        {
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("home") {
+                    home = true;
+                    Ok(())
+                } else if meta.path.is_ident("keep_alive") {
+                    keep_alive = true;
+                    Ok(())
+                } else if meta.path.is_ident("single_top") || meta.path.is_ident("single_task") {
+                    if launch_mode.is_some() {
+                        return Err(meta.error("a page can only have one launch mode"));
+                    }
+
+                    let mode = if meta.path.is_ident("single_top") {
+                        "SingleTop"
+                    } else {
+                        "SingleTask"
+                    };
+                    launch_mode = Some(Ident::new(mode, meta.path.span()));
+                    Ok(())
+                } else if meta.path.is_ident("transition") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    let kind = match value.value().as_str() {
+                        "none" => "None",
+                        "slide" => "Slide",
+                        "fade" => "Fade",
+                        "wipe" => "Wipe",
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                &value,
+                                "expected one of \"none\", \"slide\", \"fade\" or \"wipe\"",
+                            ));
+                        }
+                    };
+                    transition = Some(Ident::new(kind, value.span()));
+                    Ok(())
+                } else if meta.path.is_ident("title") {
+                    title = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("icon") {
+                    icon = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("hidden") {
+                    hidden = true;
+                    Ok(())
+                } else if meta.path.is_ident("render") {
+                    render = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("on_event") {
+                    on_event = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if let Some(hook) =
+                    LIFECYCLE_HOOKS.iter().find(|hook| meta.path.is_ident(hook))
+                {
+                    if overrides.iter().any(|(name, _)| name == hook) {
+                        return Err(meta.error(format!("`{hook}` is already overridden")));
+                    }
+
+                    let hook = Ident::new(hook, meta.path.span());
+                    overrides.push((hook, meta.value()?.parse()?));
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown #[page] option"))
+                }
+            });
+            errors.check(result);
+        }
+
+        let kind = match (&variant.fields, render) {
+            (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 => {
+                if let Some(on_event) = on_event {
+                    errors.push(syn::Error::new_spanned(
+                        on_event,
+                        "`on_event` only applies to inline pages, implement it on the page type instead",
+                    ));
+                }
+
+                PageKind::Wrapped(&fields.unnamed[0].ty)
+            }
+            (Fields::Unnamed(fields), None) => {
+                errors.push(syn::Error::new_spanned(
+                    fields,
+                    "page variants must have exactly one unnamed field, e.g. `Home(HomeScreen)`",
+                ));
+                continue;
+            }
+            (Fields::Unnamed(_), Some(render)) => {
+                errors.push(syn::Error::new_spanned(
+                    render,
+                    "`render` only applies to unit and struct variants",
+                ));
+                continue;
+            }
+            (Fields::Unit, None) => {
+                errors.push(syn::Error::new_spanned(
+                    name,
+                    "unit page variants need a render function, e.g. `#[page(render = about_screen)]`",
+                ));
+                continue;
             }
+            (Fields::Named(fields), None) => {
+                errors.push(syn::Error::new_spanned(
+                    fields,
+                    "struct page variants need a render function, e.g. `#[page(render = profile_screen)]`",
+                ));
+                continue;
+            }
+            (fields, Some(render)) => PageKind::Inline {
+                fields: fields
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .collect(),
+                render,
+                on_event,
+            },
         };
-        result.push((name, ty));
+
+        result.push(PageVariant {
+            name,
+            fields: &variant.fields,
+            kind,
+            keep_alive,
+            home,
+            launch_mode,
+            transition,
+            title,
+            icon,
+            hidden,
+            overrides,
+        });
     }
 
-    Ok(result)
+    errors.finish().map(|()| result)
 }
 
-// TODO: Base `pub` on app's `page` enum visibility.
-fn generate_page_id(variants: &[(&Ident, &Type)]) -> proc_macro2::TokenStream {
-    let ids = variants.iter().map(|(name, _)| name);
+fn generate_page_id(options: &PagesOptions, variants: &[PageVariant]) -> proc_macro2::TokenStream {
+    let PagesOptions {
+        id, vis, derives, ..
+    } = options;
+    let ids = variants.iter().map(|variant| {
+        let name = variant.name;
+
+        match variant.kind {
+            PageKind::Wrapped(_) => quote!(#name),
+            PageKind::Inline { .. } => {
+                let fields = variant.fields;
+                quote!(#name #fields)
+            }
+        }
+    });
+
+    // Pages carrying parameters have no single ID to list.
+    let all = variants
+        .iter()
+        .filter(|variant| variant.fields.is_empty() || matches!(variant.kind, PageKind::Wrapped(_)))
+        .map(|variant| variant.name);
+
+    let match_title = variants.iter().map(|variant| {
+        let name = variant.name;
+        let title = match &variant.title {
+            Some(title) => title.value(),
+            None => name.to_string(),
+        };
+
+        quote! {
+            #id::#name { .. } => #title,
+        }
+    });
+
+    let match_icon = variants.iter().map(|variant| {
+        let name = variant.name;
+        let icon = match &variant.icon {
+            Some(icon) => quote!(Some(#icon)),
+            None => quote!(None),
+        };
+
+        quote! {
+            #id::#name { .. } => #icon,
+        }
+    });
+
+    let match_hidden = variants.iter().map(|variant| {
+        let name = variant.name;
+        let hidden = variant.hidden;
+
+        quote! {
+            #id::#name { .. } => #hidden,
+        }
+    });
 
     quote! {
-        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-        pub enum pageID {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash #(, #derives)*)]
+        #vis enum #id {
             #(#ids),*
         }
+
+        impl #id {
+            pub fn all() -> &'static [#id] {
+                &[#(#id::#all),*]
+            }
+
+            pub fn visible() -> impl Iterator<Item = #id> {
+                #id::all().iter().copied().filter(|id| !id.is_hidden())
+            }
+
+            pub fn title(self) -> &'static str {
+                match self {
+                    #(#match_title)*
+                }
+            }
+
+            pub fn icon(self) -> Option<&'static str> {
+                match self {
+                    #(#match_icon)*
+                }
+            }
+
+            pub fn is_hidden(self) -> bool {
+                match self {
+                    #(#match_hidden)*
+                }
+            }
+        }
     }
 }
 
+fn state_param(generics: &Generics) -> Ident {
+    let taken = |name: &str| {
+        generics.params.iter().any(|param| match param {
+            GenericParam::Type(param) => param.ident == name,
+            GenericParam::Const(param) => param.ident == name,
+            GenericParam::Lifetime(_) => false,
+        })
+    };
+
+    let name = if taken("S") { "__RecipeState" } else { "S" };
+    Ident::new(name, proc_macro2::Span::call_site())
+}
+
+fn mentions_generics(ty: &Type, generics: &Generics) -> bool {
+    fn walk(tokens: proc_macro2::TokenStream, generics: &Generics) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => generics.params.iter().any(|param| match param {
+                GenericParam::Type(param) => param.ident == ident,
+                GenericParam::Const(param) => param.ident == ident,
+                GenericParam::Lifetime(param) => param.lifetime.ident == ident,
+            }),
+            TokenTree::Group(group) => walk(group.stream(), generics),
+            _ => false,
+        })
+    }
+
+    walk(ty.to_token_stream(), generics)
+}
+
+// Copies an inline page's fields out of a match binding, reporting a field type that can't be part
+// of the page ID at the field itself.
+fn copied(fields: &Fields) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let ty = &field.ty;
+
+            quote_spanned!(ty.span()=> ratatui_recipe::page_param::<#ty>(#ident))
+        })
+        .collect()
+}
+
 fn generate_page_state_impl(
-    enum_name: &Ident,
-    variants: &[(&Ident, &Type)],
+    input: &DeriveInput,
+    options: &PagesOptions,
+    variants: &[PageVariant],
 ) -> proc_macro2::TokenStream {
-    let where_bounds = variants.iter().map(|(_, ty)| {
-        quote! {
-            #ty : ratatui_recipe::StatefulPage<pageID, S>
+    let enum_name = &input.ident;
+    let page_id = &options.id;
+
+    let mut generics = input.generics.clone();
+    let state = match &options.state {
+        Some(state) => state.to_token_stream(),
+        None => {
+            let param = state_param(&generics);
+            generics.params.push(parse_quote!(#param: Send));
+            param.to_token_stream()
+        }
+    };
+
+    // A generic state can only be checked once it is known, so every page has to be bounded.
+    // With a concrete state only pages depending on the enum's generics need a bound; the rest
+    // are checked at the enum itself, pointing at the offending variant.
+    let where_clause = generics.make_where_clause();
+    for variant in variants {
+        let PageKind::Wrapped(ty) = variant.kind else {
+            continue;
+        };
+
+        if options.state.is_none() || mentions_generics(ty, &input.generics) {
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ratatui_recipe::StatefulPage<#page_id, #state>));
+        }
+    }
+
+    let page_trait = |ty: &Type| {
+        quote_spanned! {ty.span()=>
+            <#ty as ratatui_recipe::StatefulPage<#page_id, #state>>
+        }
+    };
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let match_new = variants.iter().map(|variant| {
+        let name = variant.name;
+
+        match &variant.kind {
+            PageKind::Wrapped(ty) => quote! {
+                #page_id::#name => #enum_name::#name(<#ty as Default>::default()),
+            },
+            PageKind::Inline { fields, .. } => quote! {
+                #page_id::#name { #(#fields),* } => #enum_name::#name { #(#fields),* },
+            },
         }
     });
 
-    let match_new = variants.iter().map(|(name, ty)| {
-        quote! {
-            pageID::#name => #enum_name::#name(#ty::default()),
+    let match_id = variants.iter().map(|variant| {
+        let name = variant.name;
+
+        match &variant.kind {
+            PageKind::Wrapped(_) => quote! {
+                #enum_name::#name(_) => #page_id::#name,
+            },
+            PageKind::Inline { fields, .. } => {
+                let copied = copied(variant.fields);
+
+                quote! {
+                #enum_name::#name { #(#fields),* } => #page_id::#name { #(#fields: #copied),* },
+                }
+            }
         }
     });
 
-    let match_draw = variants.iter().map(|(name, _)| {
+    let match_keep_alive = variants.iter().map(|variant| {
+        let name = variant.name;
+        let keep_alive = variant.keep_alive;
+
         quote! {
-            #enum_name::#name(page) => StatefulPage::draw(page, frame, state),
+            #page_id::#name { .. } => #keep_alive,
         }
     });
 
-    let match_on_event = variants.iter().map(|(name, _)| {
+    let match_launch_mode = variants.iter().map(|variant| {
+        let name = variant.name;
+        let mode = variant
+            .launch_mode
+            .clone()
+            .unwrap_or_else(|| Ident::new("Standard", name.span()));
+
         quote! {
-            #enum_name::#name(page) => StatefulPage::on_event(page, event, router, state).await,
+            #page_id::#name { .. } => ratatui_recipe::LaunchMode::#mode,
         }
     });
 
-    let match_on_enter = variants.iter().map(|(name, _)| {
+    let match_transition = variants.iter().map(|variant| {
+        let name = variant.name;
+        let transition = variant
+            .transition
+            .clone()
+            .unwrap_or_else(|| Ident::new("None", name.span()));
+
         quote! {
-            #enum_name::#name(page) => StatefulPage::on_enter(page, router, state).await,
+            #page_id::#name { .. } => ratatui_recipe::Transition::#transition,
         }
     });
 
-    let match_on_exit = variants.iter().map(|(name, _)| {
-        quote! {
-            #enum_name::#name(page) => StatefulPage::on_exit(page, router, state).await,
+    let match_event_filter = variants.iter().map(|variant| {
+        let name = variant.name;
+
+        match &variant.kind {
+            PageKind::Wrapped(ty) => {
+                let page = page_trait(ty);
+
+                quote! {
+                    #enum_name::#name(page) => #page::event_filter(page),
+                }
+            }
+            PageKind::Inline { .. } => quote! {
+                #enum_name::#name { .. } => None,
+            },
         }
     });
 
-    let match_on_pause = variants.iter().map(|(name, _)| {
-        quote! {
-            #enum_name::#name(page) => StatefulPage::on_pause(page, router, state).await,
+    let match_draw = variants.iter().map(|variant| {
+        let name = variant.name;
+
+        match &variant.kind {
+            PageKind::Wrapped(ty) => {
+                let page = page_trait(ty);
+
+                quote! {
+                    #enum_name::#name(page) => #page::draw(page, frame, state),
+                }
+            }
+            PageKind::Inline { fields, render, .. } => {
+                let copied = copied(variant.fields);
+
+                quote! {
+                    #enum_name::#name { #(#fields),* } => #render(frame #(, #copied)*),
+                }
+            }
         }
     });
 
-    let match_on_resume = variants.iter().map(|(name, _)| {
-        quote! {
-            #enum_name::#name(page) => StatefulPage::on_resume(page, router, state).await,
+    let match_draw_damaged = variants.iter().map(|variant| {
+        let name = variant.name;
+
+        match &variant.kind {
+            PageKind::Wrapped(ty) => {
+                let page = page_trait(ty);
+
+                quote! {
+                    #enum_name::#name(page) => #page::draw_damaged(page, frame, damage, state),
+                }
+            }
+            PageKind::Inline { .. } => quote! {
+                #enum_name::#name { .. } => ratatui_recipe::PageState::draw(self, frame, state),
+            },
         }
     });
 
-    let match_task = variants.iter().map(|(name, _)| {
-        quote! {
-            #enum_name::#name(page) => StatefulPage::task(page, router, state).await,
+    let match_on_event = variants.iter().map(|variant| {
+        let name = variant.name;
+
+        match &variant.kind {
+            PageKind::Wrapped(ty) => {
+                let page = page_trait(ty);
+
+                quote! {
+                    #enum_name::#name(page) => #page::on_event(page, event, router, state).await,
+                }
+            }
+            PageKind::Inline {
+                fields,
+                on_event: Some(on_event),
+                ..
+            } => {
+                let copied = copied(variant.fields);
+
+                quote! {
+                    #enum_name::#name { #(#fields),* } => #on_event(event, router #(, #copied)*).await,
+                }
+            }
+            PageKind::Inline { on_event: None, .. } => quote! {
+                #enum_name::#name { .. } => {}
+            },
         }
     });
 
+    // Inline pages have no type to forward to, so they get the same defaults as `Page`.
+    let forward = |hook: proc_macro2::TokenStream, inline: proc_macro2::TokenStream| {
+        variants
+            .iter()
+            .map(|variant| {
+                let name = variant.name;
+
+                match variant.kind {
+                    PageKind::Wrapped(ty) => {
+                        let page = page_trait(ty);
+
+                        quote! {
+                            #enum_name::#name(page) => #page::#hook.await,
+                        }
+                    }
+                    PageKind::Inline { .. } => quote! {
+                        #enum_name::#name { .. } => #inline,
+                    },
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // A variant's `#[page(on_enter = ..)]` runs after a wrapped page's own hook, with the page and
+    // state, and takes the place of the no-op hook of an inline page.
+    let lifecycle = |hook: &str| {
+        let hook_ident = Ident::new(hook, proc_macro2::Span::call_site());
+        let defaults = forward(quote!(#hook_ident(page, router, state)), quote!({}));
+
+        variants
+            .iter()
+            .zip(defaults)
+            .map(|(variant, default)| {
+                let name = variant.name;
+                let Some((_, path)) = variant.overrides.iter().find(|(name, _)| name == hook)
+                else {
+                    return default;
+                };
+
+                match &variant.kind {
+                    PageKind::Wrapped(ty) => {
+                        let page = page_trait(ty);
+
+                        quote! {
+                            #enum_name::#name(page) => {
+                                #page::#hook_ident(page, router.clone(), state).await;
+                                #path(page, router, state).await
+                            }
+                        }
+                    }
+                    PageKind::Inline { fields, .. } => {
+                        let copied = copied(variant.fields);
+
+                        quote! {
+                            #enum_name::#name { #(#fields),* } => #path(router #(, #copied)*).await,
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let match_on_enter = lifecycle("on_enter");
+    let match_on_exit = lifecycle("on_exit");
+    let match_on_pause = lifecycle("on_pause");
+    let match_on_resume = lifecycle("on_resume");
+    let match_task = forward(
+        quote!(task(page, router, state)),
+        quote!(std::future::pending().await),
+    );
+    let match_before_leave = forward(
+        quote!(before_leave(page, action, router, state)),
+        quote!(ratatui_recipe::Guard::Allow),
+    );
+    let match_on_signal = forward(
+        quote!(on_signal(page, signal, router, state)),
+        quote!(router.unguarded().exit()),
+    );
+
     let page_state_impl = quote! {
-        impl<S> ratatui_recipe::PageState<S> for #enum_name
-        where
-            #( #where_bounds, )*
+        impl #impl_generics ratatui_recipe::PageState<#state> for #enum_name #ty_generics
+        #where_clause
         {
-            type ID = pageID;
+            type ID = #page_id;
 
             fn new(id: Self::ID) -> Self {
                 match id {
@@ -140,63 +779,163 @@ fn generate_page_state_impl(
                 }
             }
 
-            fn draw(&mut self, frame: &mut ratatui::Frame, state: &S) {
-                use ratatui_recipe::StatefulPage;
+            fn id(&self) -> Self::ID {
+                match self {
+                    #(#match_id)*
+                }
+            }
+
+            fn keep_alive(id: Self::ID) -> bool {
+                match id {
+                    #(#match_keep_alive)*
+                }
+            }
+
+            fn launch_mode(id: Self::ID) -> ratatui_recipe::LaunchMode {
+                match id {
+                    #(#match_launch_mode)*
+                }
+            }
+
+            fn transition(id: Self::ID) -> ratatui_recipe::Transition {
+                match id {
+                    #(#match_transition)*
+                }
+            }
+
+            fn event_filter(&self) -> Option<ratatui_recipe::EventFilter> {
+                match self {
+                    #(#match_event_filter)*
+                }
+            }
 
+            fn draw(&mut self, frame: &mut ratatui::Frame, state: &#state) {
                 match self {
                     #(#match_draw)*
                 }
             }
 
-            async fn on_event(&mut self, event: ratatui::crossterm::event::Event, router: ratatui_recipe::Router<Self::ID>, state: &mut S) {
-                use ratatui_recipe::StatefulPage;
+            fn draw_damaged(&mut self, frame: &mut ratatui::Frame, damage: &[ratatui::layout::Rect], state: &#state) {
+                match self {
+                    #(#match_draw_damaged)*
+                }
+            }
 
+            async fn on_event(&mut self, event: ratatui::crossterm::event::Event, router: ratatui_recipe::Router<Self::ID>, state: &mut #state) {
                 match self {
                     #(#match_on_event)*
                 }
             }
 
-            async fn on_enter(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut S) {
-                use ratatui_recipe::StatefulPage;
-
+            async fn on_enter(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut #state) {
                 match self {
                     #(#match_on_enter)*
                 }
             }
 
-            async fn on_exit(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut S) {
-                use ratatui_recipe::StatefulPage;
-
+            async fn on_exit(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut #state) {
                 match self {
                     #(#match_on_exit)*
                 }
             }
 
-            async fn on_pause(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut S) {
-                use ratatui_recipe::StatefulPage;
-
+            async fn on_pause(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut #state) {
                 match self {
                     #(#match_on_pause)*
                 }
             }
 
-            async fn on_resume(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut S) {
-                use ratatui_recipe::StatefulPage;
-
+            async fn on_resume(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut #state) {
                 match self {
                     #(#match_on_resume)*
                 }
             }
 
-            async fn task(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut S) {
-                use ratatui_recipe::StatefulPage;
-
+            async fn task(&mut self, router: ratatui_recipe::Router<Self::ID>, state: &mut #state) {
                 match self {
                     #(#match_task)*
                 }
             }
+
+            async fn before_leave(
+                &mut self,
+                action: &ratatui_recipe::RouterAction<Self::ID>,
+                router: ratatui_recipe::Router<Self::ID>,
+                state: &mut #state,
+            ) -> ratatui_recipe::Guard<Self::ID> {
+                match self {
+                    #(#match_before_leave)*
+                }
+            }
+
+            async fn on_signal(&mut self, signal: ratatui_recipe::Signal, router: ratatui_recipe::Router<Self::ID>, state: &mut #state) {
+                match self {
+                    #(#match_on_signal)*
+                }
+            }
         }
     };
 
     page_state_impl
 }
+
+fn generate_default_impl(
+    input: &DeriveInput,
+    variants: &[PageVariant],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut homes = variants.iter().filter(|variant| variant.home);
+
+    let Some(home) = homes.next() else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "no home page: mark one variant with #[default]",
+        ));
+    };
+
+    let mut errors = Errors::default();
+    for other in homes {
+        errors.push(syn::Error::new_spanned(
+            other.name,
+            "only one variant can be marked with #[default]",
+        ));
+    }
+    errors.finish()?;
+
+    let enum_name = &input.ident;
+    let name = home.name;
+    let mut generics = input.generics.clone();
+    let value = match &home.kind {
+        PageKind::Wrapped(ty) => {
+            if mentions_generics(ty, &input.generics) {
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote!(#ty: Default));
+            }
+
+            quote!(#enum_name::#name(<#ty as Default>::default()))
+        }
+        PageKind::Inline { .. } => {
+            let fields = home.fields.iter().map(|field| {
+                let ident = &field.ident;
+                let ty = &field.ty;
+
+                quote_spanned!(ty.span()=> #ident: <#ty as Default>::default())
+            });
+
+            quote!(#enum_name::#name { #(#fields),* })
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics Default for #enum_name #ty_generics
+        #where_clause
+        {
+            fn default() -> Self {
+                #value
+            }
+        }
+    })
+}
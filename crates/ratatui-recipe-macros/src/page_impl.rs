@@ -0,0 +1,259 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{
+    Attribute, FnArg, ImplItem, ImplItemFn, ItemImpl, Token, Type,
+    parse::{Parse, ParseStream},
+};
+
+use crate::Errors;
+
+pub(crate) struct PageImplArgs {
+    id: Type,
+    state: Option<Type>,
+}
+
+impl Parse for PageImplArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let id = input.parse()?;
+        let mut state = None;
+
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "state" {
+                return Err(syn::Error::new_spanned(key, "unknown #[page_impl] option"));
+            }
+
+            input.parse::<Token![=]>()?;
+            state = Some(input.parse()?);
+        }
+
+        Ok(PageImplArgs { id, state })
+    }
+}
+
+const HOOKS: &[&str] = &[
+    "init",
+    "draw",
+    "on_event",
+    "on_enter",
+    "on_exit",
+    "on_pause",
+    "on_resume",
+    "task",
+    "before_leave",
+    "on_signal",
+];
+
+struct Hook {
+    name: &'static str,
+    method: Ident,
+    is_async: bool,
+}
+
+// The impl block is always emitted, minus the hook attributes, so a mistake in one hook doesn't
+// cascade into errors about the methods and imports it uses.
+pub(crate) fn page_impl(args: PageImplArgs, mut item: ItemImpl) -> TokenStream {
+    match expand(args, &mut item) {
+        Ok(tokens) => quote!(#item #tokens),
+        Err(err) => {
+            let err = err.into_compile_error();
+            quote!(#item #err)
+        }
+    }
+}
+
+fn expand(args: PageImplArgs, item: &mut ItemImpl) -> syn::Result<TokenStream> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(syn::Error::new_spanned(
+            path,
+            "#[page_impl] goes on an inherent impl block, e.g. `impl HomeScreen { .. }`",
+        ));
+    }
+
+    let mut errors = Errors::default();
+    let mut hooks: Vec<Hook> = Vec::new();
+
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(method) = impl_item else {
+            continue;
+        };
+
+        for name in take_hooks(&mut method.attrs) {
+            if hooks.iter().any(|hook| hook.name == name) {
+                errors.push(syn::Error::new_spanned(
+                    &method.sig.ident,
+                    format!("only one method can be marked with #[{name}]"),
+                ));
+                continue;
+            }
+
+            errors.check(check_signature(name, method));
+            hooks.push(Hook {
+                name,
+                method: method.sig.ident.clone(),
+                is_async: method.sig.asyncness.is_some(),
+            });
+        }
+    }
+
+    if !hooks.iter().any(|hook| hook.name == "draw") {
+        errors.push(syn::Error::new_spanned(
+            &item.self_ty,
+            "a page needs a method marked with #[draw]",
+        ));
+    }
+
+    errors.finish()?;
+
+    let page_impl = generate_page_impl(&args, item, &hooks);
+    let default_impl = hooks
+        .iter()
+        .find(|hook| hook.name == "init")
+        .map(|hook| generate_default_impl(item, hook));
+
+    Ok(quote! {
+        #page_impl
+
+        #default_impl
+    })
+}
+
+fn take_hooks(attrs: &mut Vec<Attribute>) -> Vec<&'static str> {
+    let mut hooks = Vec::new();
+
+    attrs.retain(
+        |attr| match HOOKS.iter().find(|hook| attr.path().is_ident(hook)) {
+            Some(hook) => {
+                hooks.push(*hook);
+                false
+            }
+            None => true,
+        },
+    );
+
+    hooks
+}
+
+fn check_signature(name: &str, method: &ImplItemFn) -> syn::Result<()> {
+    let sig = &method.sig;
+    let takes_self = matches!(sig.inputs.first(), Some(FnArg::Receiver(_)));
+
+    match name {
+        "init" if takes_self => Err(syn::Error::new_spanned(
+            sig,
+            "#[init] constructs the page, so it can't take `self`",
+        )),
+        "init" | "draw" if sig.asyncness.is_some() => Err(syn::Error::new_spanned(
+            sig.asyncness,
+            format!("#[{name}] can't be async"),
+        )),
+        "init" => Ok(()),
+        _ if !takes_self => Err(syn::Error::new_spanned(
+            sig,
+            format!("#[{name}] needs a `&mut self` receiver"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn generate_page_impl(args: &PageImplArgs, item: &ItemImpl, hooks: &[Hook]) -> TokenStream {
+    let PageImplArgs { id, state } = args;
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    let state_param = state.as_ref().map(|state| quote!(, state: &mut #state));
+    let state_arg = state.as_ref().map(|_| quote!(, state));
+
+    let methods = hooks.iter().map(|hook| {
+        let Hook {
+            method, is_async, ..
+        } = hook;
+        let call = |args: TokenStream| {
+            if *is_async {
+                quote!(Self::#method(self, #args).await)
+            } else {
+                quote!(Self::#method(self, #args))
+            }
+        };
+
+        match hook.name {
+            "draw" => {
+                let state_param = state.as_ref().map(|state| quote!(, state: &#state));
+
+                quote! {
+                    fn draw(&mut self, frame: &mut ratatui::Frame #state_param) {
+                        Self::#method(self, frame #state_arg)
+                    }
+                }
+            }
+            "on_event" => {
+                let call = call(quote!(event, router #state_arg));
+
+                quote! {
+                    async fn on_event(&mut self, event: ratatui::crossterm::event::Event, router: ratatui_recipe::Router<#id> #state_param) {
+                        #call
+                    }
+                }
+            }
+            "before_leave" => {
+                let call = call(quote!(action, router #state_arg));
+
+                quote! {
+                    async fn before_leave(
+                        &mut self,
+                        action: &ratatui_recipe::RouterAction<#id>,
+                        router: ratatui_recipe::Router<#id>
+                        #state_param
+                    ) -> ratatui_recipe::Guard<#id> {
+                        #call
+                    }
+                }
+            }
+            "on_signal" => {
+                let call = call(quote!(signal, router #state_arg));
+
+                quote! {
+                    async fn on_signal(&mut self, signal: ratatui_recipe::Signal, router: ratatui_recipe::Router<#id> #state_param) {
+                        #call
+                    }
+                }
+            }
+            "init" => quote!(),
+            name => {
+                let name = Ident::new(name, method.span());
+                let call = call(quote!(router #state_arg));
+
+                quote! {
+                    async fn #name(&mut self, router: ratatui_recipe::Router<#id> #state_param) {
+                        #call
+                    }
+                }
+            }
+        }
+    });
+
+    let page_trait = match state {
+        Some(state) => quote!(ratatui_recipe::StatefulPage<#id, #state>),
+        None => quote!(ratatui_recipe::Page<#id>),
+    };
+
+    quote! {
+        impl #impl_generics #page_trait for #self_ty #where_clause {
+            #(#methods)*
+        }
+    }
+}
+
+fn generate_default_impl(item: &ItemImpl, init: &Hook) -> TokenStream {
+    let self_ty = &item.self_ty;
+    let method = &init.method;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics Default for #self_ty #where_clause {
+            fn default() -> Self {
+                Self::#method()
+            }
+        }
+    }
+}
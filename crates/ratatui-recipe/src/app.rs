@@ -1,56 +1,113 @@
-use std::{collections::VecDeque, io::Result};
+use std::{
+    collections::VecDeque,
+    future,
+    io::{Error, Result, stdout},
+};
 
-use ratatui::crossterm::event::{self, Event};
-use tokio::sync::mpsc;
+use ratatui::{
+    DefaultTerminal, Terminal, TerminalOptions, Viewport,
+    backend::Backend,
+    buffer::Buffer,
+    crossterm::{
+        event::{
+            DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+            EnableFocusChange, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+            KeyModifiers, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+        },
+        execute,
+        terminal::{self, EnterAlternateScreen},
+    },
+};
+use tokio::{
+    sync::mpsc,
+    time::{self, Interval, MissedTickBehavior},
+};
 
 use crate::{
+    builder::{AppBuilder, Settings, TerminalFeatures},
+    bus::EventReceiver,
+    cache::PageCache,
+    history::History,
     page::PageState,
-    router::{Router, RouterAction},
+    redraw::{Redraw, Redraws, keep_undamaged},
+    router::{Guard, LaunchMode, Navigation, Router, RouterAction, Routes},
+    signal::{Received, Signals},
+    store::{Reducer, Store},
+    transition::{Transition, snapshot},
 };
 
+#[cfg(unix)]
+use crate::signal;
+
 pub struct App<S = ()> {
-    event_bus: mpsc::UnboundedReceiver<Event>,
     state: S,
+    settings: Settings<S>,
 }
 
 impl App<()> {
     pub fn new() -> Self {
-        let (bus_tx, bus_rx) = mpsc::unbounded_channel();
-
-        tokio::task::spawn_blocking(move || {
-            loop {
-                if let Ok(event) = event::read()
-                    && bus_tx.send(event).is_err()
-                {
-                    break;
-                }
-            }
-        });
+        AppBuilder::new().build()
+    }
+}
 
-        Self {
-            event_bus: bus_rx,
-            state: (),
-        }
+impl<S> App<Store<S>>
+where
+    S: Reducer,
+{
+    pub fn store(state: S) -> Self {
+        AppBuilder::store(state).build()
+    }
+}
+
+impl<S> App<History<S>> {
+    pub fn history(state: S, depth: usize) -> Self {
+        AppBuilder::history(state, depth).build()
     }
 }
 
 impl<S> App<S> {
     pub fn stateful(state: S) -> Self {
-        let (bus_tx, bus_rx) = mpsc::unbounded_channel();
-
-        tokio::task::spawn_blocking(move || {
-            loop {
-                if let Ok(event) = event::read()
-                    && bus_tx.send(event).is_err()
-                {
-                    break;
-                }
+        AppBuilder::stateful(state).build()
+    }
+
+    pub fn builder(state: S) -> AppBuilder<S> {
+        AppBuilder::stateful(state)
+    }
+
+    pub(crate) fn from_parts(state: S, settings: Settings<S>) -> Self {
+        App { state, settings }
+    }
+
+    // A redirect is final: its target is not run through the middleware again.
+    fn admit<ID>(&self, routes: &Routes<ID, S>, id: ID) -> Option<ID>
+    where
+        ID: Copy,
+    {
+        for middleware in &routes.middleware {
+            match middleware(id, &self.state) {
+                Guard::Allow => {}
+                Guard::Cancel => return None,
+                Guard::Redirect(target) => return Some(target),
             }
-        });
+        }
+
+        Some(id)
+    }
 
-        Self {
-            event_bus: bus_rx,
-            state,
+    fn home<P>(&self, routes: &Routes<P::ID, S>) -> Option<P>
+    where
+        P: PageState<S>,
+    {
+        let page = match routes.initial_route {
+            Some(id) => P::new(id),
+            None => P::default(),
+        };
+        let id = page.id();
+
+        match self.admit(routes, id) {
+            Some(target) if target == id => Some(page),
+            Some(target) => Some(P::new(target)),
+            None => None,
         }
     }
 
@@ -58,11 +115,60 @@ impl<S> App<S> {
     where
         P: PageState<S>,
     {
-        let mut terminal = ratatui::init();
-        let mut pages = VecDeque::from([P::default()]);
+        self.run_with::<P>(Routes::new()).await
+    }
+
+    pub async fn run_with<P>(&mut self, routes: Routes<P::ID, S>) -> Result<()>
+    where
+        P: PageState<S>,
+    {
+        let home = self
+            .home::<P>(&routes)
+            .ok_or_else(|| Error::other("the initial route was rejected by middleware"))?;
+
+        let mut signals = Signals::listen(
+            self.settings.handle_signals,
+            self.settings.suspend_on_ctrl_z,
+        )?;
+        let mut events = EventReceiver::spawn(self.settings.event_bus);
+        let mut terminal = match &self.settings.viewport {
+            Viewport::Fullscreen => ratatui::init(),
+            viewport => ratatui::init_with_options(TerminalOptions {
+                viewport: viewport.clone(),
+            }),
+        };
+
+        // Every way out of the loop, errors included, goes through the same teardown.
+        let result = match enable_features(&self.settings.terminal) {
+            Ok(()) => {
+                self.event_loop(&routes, home, &mut terminal, &mut events, &mut signals)
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+
+        events.close().await;
+        restore_terminal(&mut terminal, &self.settings);
+        result
+    }
+
+    async fn event_loop<P>(
+        &mut self,
+        routes: &Routes<P::ID, S>,
+        home: P,
+        terminal: &mut DefaultTerminal,
+        events: &mut EventReceiver,
+        signals: &mut Signals,
+    ) -> Result<()>
+    where
+        P: PageState<S>,
+    {
+        let mut pages = VecDeque::from([home]);
+        let mut cache = PageCache::<P, S>::new(self.settings.keep_alive);
 
         let (bus_tx, mut bus_rx) = mpsc::unbounded_channel();
-        let router = Router::new(bus_tx);
+        let (suspend_tx, mut suspend_rx) = mpsc::unbounded_channel();
+        let router = Router::new(bus_tx, suspend_tx);
 
         pages
             .back_mut()
@@ -70,103 +176,428 @@ impl<S> App<S> {
             .on_enter(router.clone(), &mut self.state)
             .await;
 
-        let mut draw = true;
+        let mut ticks = self.settings.tick_rate.map(|tick_rate| {
+            let mut interval = time::interval(tick_rate);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            interval
+        });
+
+        let mut redraws = Redraws::new(self.settings.damage_tracking);
+        let mut last_frame: Option<Buffer> = None;
 
         loop {
             let page = pages.back_mut().expect("uhoh");
 
-            if draw {
-                terminal
-                    .draw(|f| page.draw(f, &self.state))
-                    .inspect_err(|_| {
-                        ratatui::restore();
-                    })?;
+            let pending = !events.is_empty() || !bus_rx.is_empty();
+
+            if let Some(redraw) = redraws.take(pending) {
+                let damaged = match (&redraw, &last_frame) {
+                    (Redraw::Damage(damage), Some(last_frame)) => Some((damage, last_frame)),
+                    _ => None,
+                };
 
-                draw = false;
+                let completed = terminal.draw(|f| match damaged {
+                    Some((damage, last_frame)) if last_frame.area == f.area() => {
+                        page.draw_damaged(f, damage, &self.state);
+                        keep_undamaged(f.buffer_mut(), last_frame, damage);
+                    }
+                    _ => page.draw(f, &self.state),
+                })?;
+
+                if self.settings.damage_tracking {
+                    match &mut last_frame {
+                        Some(last_frame) if last_frame.area == completed.buffer.area => {
+                            last_frame
+                                .content
+                                .clone_from_slice(&completed.buffer.content);
+                        }
+                        last_frame => *last_frame = Some(completed.buffer.clone()),
+                    }
+                }
             }
 
             tokio::select! {
                 _ = page.task(router.clone(), &mut self.state) => {},
-                Some(event) = self.event_bus.recv() => {
+                event = events.recv() => {
                     if let Event::Resize(_, _) = event {
-                        draw = true;
+                        redraws.full();
                     }
 
-                    page.on_event(event, router.clone(), &mut self.state).await;
-                },
-                Some(action) = bus_rx.recv() => {
-                    match action {
-                        RouterAction::PUSH(id) => {
-                            page.on_pause(router.clone(), &mut self.state).await;
-
-                            let mut page = P::new(id);
-                            page.on_enter(router.clone(), &mut self.state).await;
+                    let filter = self.settings.event_filter_for(page);
 
-                            pages.push_back(page);
+                    if self.settings.suspend_on_ctrl_z && is_ctrl_z(&event) {
+                        router.suspend(stop_process);
+                    } else if filter.allows(&event) {
+                        page.on_event(event, router.clone(), &mut self.state).await;
+                    }
+                },
+                _ = tick(&mut ticks) => {
+                    redraws.full();
+                },
+                received = signals.recv() => match received {
+                    Received::Signal(signal) => {
+                        page.on_signal(signal, router.clone(), &mut self.state).await;
+                    }
+                    #[cfg(unix)]
+                    Received::Suspend => router.suspend(stop_process),
+                },
+                Some(task) = suspend_rx.recv() => {
+                    events.close().await;
+                    restore_terminal(terminal, &self.settings);
 
-                            draw = true;
-                        }
-                        RouterAction::REPLACE(id) => {
-                            let mut old_page = pages.pop_back().unwrap();
-                            old_page.on_exit(router.clone(), &mut self.state).await;
+                    task().await;
 
-                            let mut new_page = P::new(id);
-                            new_page.on_enter(router.clone(), &mut self.state).await;
-                            pages.push_back(new_page);
+                    resume_terminal(terminal, &self.settings)?;
+                    *events = EventReceiver::spawn(self.settings.event_bus);
 
-                            draw = true;
+                    redraws.full();
+                },
+                Some(Navigation { action, guarded, transition }) = bus_rx.recv() => {
+                    let verdict = if guarded {
+                        guard(&mut pages, &action, &router, &mut self.state).await
+                    } else {
+                        Guard::Allow
+                    };
+
+                    let action = match verdict {
+                        Guard::Allow => Some(action),
+                        Guard::Cancel => None,
+                        Guard::Redirect(id) => Some(RouterAction::PUSH(id)),
+                    };
+
+                    let action = action.and_then(|action| match action {
+                        RouterAction::PUSH(id) => self.admit(routes, id).map(RouterAction::PUSH),
+                        RouterAction::LAUNCH(id, mode) => {
+                            self.admit(routes, id).map(|id| RouterAction::LAUNCH(id, mode))
                         }
-                        RouterAction::BACK => {
-                            if pages.len() > 1 {
+                        RouterAction::REPLACE(id) => self.admit(routes, id).map(RouterAction::REPLACE),
+                        action => Some(action),
+                    });
+
+                    if let Some(action) = action {
+                        // Only actions that swap the top page animate.
+                        let transition = match &action {
+                            RouterAction::PUSH(id)
+                            | RouterAction::LAUNCH(id, _)
+                            | RouterAction::REPLACE(id) => {
+                                transition.unwrap_or_else(|| P::transition(*id))
+                            }
+                            RouterAction::BACK => transition
+                                .unwrap_or_else(|| P::transition(pages.back().unwrap().id())),
+                            RouterAction::RESTART => transition.unwrap_or_default(),
+                            _ => Transition::None,
+                        };
+                        let forward = !matches!(action, RouterAction::BACK);
+                        let area = terminal.get_frame().area();
+
+                        let outgoing = (transition != Transition::None).then(|| {
+                            let page = pages.back_mut().unwrap();
+                            snapshot(area, |f| page.draw(f, &self.state))
+                        });
+
+                        let mut replaced = false;
+
+                        match action {
+                            RouterAction::PUSH(id) => {
+                                let mode = P::launch_mode(id);
+                                replaced = launch(&mut pages, &mut cache, &router, &mut self.state, id, mode).await;
+                            }
+                            RouterAction::LAUNCH(id, mode) => {
+                                replaced = launch(&mut pages, &mut cache, &router, &mut self.state, id, mode).await;
+                            }
+                            RouterAction::REPLACE(id) => {
                                 let mut old_page = pages.pop_back().unwrap();
                                 old_page.on_exit(router.clone(), &mut self.state).await;
+                                cache.retire(old_page);
 
-                                let current_page = pages.back_mut().unwrap();
-                                current_page.on_resume(router.clone(), &mut self.state).await;
+                                let mut new_page = cache.take_or_new(id);
+                                new_page.on_enter(router.clone(), &mut self.state).await;
+                                pages.push_back(new_page);
 
-                                draw = true;
+                                replaced = true;
                             }
-                        }
-                        RouterAction::CLEAR => {
-                            let current_page = pages.pop_back().unwrap();
+                            RouterAction::BACK => {
+                                if pages.len() > 1 {
+                                    let mut old_page = pages.pop_back().unwrap();
+                                    old_page.on_exit(router.clone(), &mut self.state).await;
+                                    cache.retire(old_page);
 
-                            while let Some(mut old_page) = pages.pop_back() {
-                                old_page.on_exit(router.clone(), &mut self.state).await;
-                            }
+                                    let current_page = pages.back_mut().unwrap();
+                                    current_page.on_resume(router.clone(), &mut self.state).await;
 
-                            pages.push_back(current_page);
-                        }
-                        RouterAction::RESTART => {
-                            while let Some(mut old_page) = pages.pop_back() {
-                                old_page.on_exit(router.clone(), &mut self.state).await;
+                                    replaced = true;
+                                }
                             }
+                            RouterAction::CLEAR => {
+                                let current_page = pages.pop_back().unwrap();
 
-                            let mut new_page = P::default();
-                            new_page.on_enter(router.clone(), &mut self.state).await;
-                            pages.push_back(new_page);
+                                while let Some(mut old_page) = pages.pop_back() {
+                                    old_page.on_exit(router.clone(), &mut self.state).await;
+                                    cache.retire(old_page);
+                                }
+
+                                pages.push_back(current_page);
+                            }
+                            RouterAction::RESTART => {
+                                if let Some(mut new_page) = self.home::<P>(routes) {
+                                    while let Some(mut old_page) = pages.pop_back() {
+                                        old_page.on_exit(router.clone(), &mut self.state).await;
+                                        cache.retire(old_page);
+                                    }
+
+                                    new_page.on_enter(router.clone(), &mut self.state).await;
+                                    pages.push_back(new_page);
+
+                                    replaced = true;
+                                }
+                            }
+                            RouterAction::REDRAW => {
+                                redraws.full();
+                            }
+                            RouterAction::DAMAGE(area) => {
+                                redraws.damage(area);
+                            }
+                            RouterAction::UNDO => {
+                                if (self.settings.hooks.undo)(&mut self.state) {
+                                    redraws.full();
+                                }
+                            }
+                            RouterAction::REDO => {
+                                if (self.settings.hooks.redo)(&mut self.state) {
+                                    redraws.full();
+                                }
+                            }
+                            RouterAction::EXIT => {
+                                while let Some(mut old_page) = pages.pop_back() {
+                                    old_page.on_exit(router.clone(), &mut self.state).await;
+                                }
 
-                            draw = true;
+                                break;
+                            }
                         }
-                        RouterAction::REDRAW => {
-                            draw = true;
+
+                        if replaced {
+                            redraws.full();
                         }
-                        RouterAction::EXIT => {
-                            while let Some(mut old_page) = pages.pop_back() {
-                                old_page.on_exit(router.clone(), &mut self.state).await;
-                            }
 
-                            break;
+                        if let Some(outgoing) = outgoing
+                            && replaced
+                        {
+                            let page = pages.back_mut().unwrap();
+                            let incoming = snapshot(area, |f| page.draw(f, &self.state));
+
+                            transition.play(terminal, &outgoing, &incoming, forward).await?;
                         }
                     }
                 }
             }
+
+            if (self.settings.hooks.changed)(&mut self.state) {
+                redraws.full();
+            }
         }
 
-        ratatui::restore();
         Ok(())
     }
 }
 
+fn is_ctrl_z(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Key(KeyEvent {
+            code: KeyCode::Char('z'),
+            modifiers: KeyModifiers::CONTROL,
+            kind: KeyEventKind::Press,
+            ..
+        })
+    )
+}
+
+async fn stop_process() {
+    // Raw mode swallows Ctrl+Z, so raise SIGTSTP ourselves once the terminal is restored.
+    #[cfg(unix)]
+    signal::stop();
+}
+
+fn enable_features(features: &TerminalFeatures) -> Result<()> {
+    let mut stdout = stdout();
+
+    if features.mouse_capture {
+        execute!(stdout, EnableMouseCapture)?;
+    }
+
+    if features.bracketed_paste {
+        execute!(stdout, EnableBracketedPaste)?;
+    }
+
+    if features.focus_change {
+        execute!(stdout, EnableFocusChange)?;
+    }
+
+    if let Some(flags) = features.keyboard_enhancement
+        && terminal::supports_keyboard_enhancement().unwrap_or(false)
+    {
+        execute!(stdout, PushKeyboardEnhancementFlags(flags))?;
+    }
+
+    Ok(())
+}
+
+fn disable_features(features: &TerminalFeatures) {
+    let mut stdout = stdout();
+
+    if features.keyboard_enhancement.is_some()
+        && terminal::supports_keyboard_enhancement().unwrap_or(false)
+    {
+        let _ = execute!(stdout, PopKeyboardEnhancementFlags);
+    }
+
+    if features.focus_change {
+        let _ = execute!(stdout, DisableFocusChange);
+    }
+
+    if features.bracketed_paste {
+        let _ = execute!(stdout, DisableBracketedPaste);
+    }
+
+    if features.mouse_capture {
+        let _ = execute!(stdout, DisableMouseCapture);
+    }
+}
+
+fn restore_terminal<S>(terminal: &mut DefaultTerminal, settings: &Settings<S>) {
+    disable_features(&settings.terminal);
+
+    if settings.viewport == Viewport::Fullscreen {
+        ratatui::restore();
+        return;
+    }
+
+    let _ = park_cursor(terminal);
+    let _ = terminal::disable_raw_mode();
+}
+
+// Park the cursor under the inline viewport so its last frame stays in the scrollback.
+fn park_cursor<B>(terminal: &mut Terminal<B>) -> std::result::Result<(), B::Error>
+where
+    B: Backend,
+{
+    let area = terminal.get_frame().area();
+    terminal.set_cursor_position((0, area.bottom().saturating_sub(1)))?;
+    terminal.show_cursor()?;
+    terminal.backend_mut().append_lines(1)
+}
+
+fn resume_terminal<S>(terminal: &mut DefaultTerminal, settings: &Settings<S>) -> Result<()> {
+    terminal::enable_raw_mode()?;
+
+    if settings.viewport == Viewport::Fullscreen {
+        execute!(stdout(), EnterAlternateScreen)?;
+    }
+
+    enable_features(&settings.terminal)?;
+    terminal.clear()
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => future::pending().await,
+    }
+}
+
+async fn guard<P, S>(
+    pages: &mut VecDeque<P>,
+    action: &RouterAction<P::ID>,
+    router: &Router<P::ID>,
+    state: &mut S,
+) -> Guard<P::ID>
+where
+    P: PageState<S>,
+{
+    let leaving = match action {
+        // A single_task launch pops every page above an existing instance.
+        RouterAction::PUSH(id) | RouterAction::LAUNCH(id, _) => {
+            let mode = match action {
+                RouterAction::LAUNCH(_, mode) => *mode,
+                _ => P::launch_mode(*id),
+            };
+
+            match pages.iter().position(|page| page.id() == *id) {
+                Some(index) if mode == LaunchMode::SingleTask => index + 1..pages.len(),
+                _ => return Guard::Allow,
+            }
+        }
+        RouterAction::BACK if pages.len() > 1 => pages.len() - 1..pages.len(),
+        RouterAction::REPLACE(_) => pages.len() - 1..pages.len(),
+        RouterAction::CLEAR => 0..pages.len() - 1,
+        RouterAction::RESTART | RouterAction::EXIT => 0..pages.len(),
+        _ => return Guard::Allow,
+    };
+
+    for index in leaving.rev() {
+        match pages[index]
+            .before_leave(action, router.clone(), state)
+            .await
+        {
+            Guard::Allow => {}
+            verdict => return verdict,
+        }
+    }
+
+    Guard::Allow
+}
+
+async fn launch<P, S>(
+    pages: &mut VecDeque<P>,
+    cache: &mut PageCache<P, S>,
+    router: &Router<P::ID>,
+    state: &mut S,
+    id: P::ID,
+    mode: LaunchMode,
+) -> bool
+where
+    P: PageState<S>,
+{
+    match mode {
+        LaunchMode::Standard => {}
+        LaunchMode::SingleTop => {
+            if pages.back().is_some_and(|page| page.id() == id) {
+                return false;
+            }
+        }
+        LaunchMode::SingleTask => {
+            if let Some(index) = pages.iter().position(|page| page.id() == id) {
+                if index + 1 == pages.len() {
+                    return false;
+                }
+
+                while pages.len() > index + 1 {
+                    let mut old_page = pages.pop_back().unwrap();
+                    old_page.on_exit(router.clone(), state).await;
+                    cache.retire(old_page);
+                }
+
+                let current_page = pages.back_mut().unwrap();
+                current_page.on_resume(router.clone(), state).await;
+
+                return true;
+            }
+        }
+    }
+
+    let current_page = pages.back_mut().unwrap();
+    current_page.on_pause(router.clone(), state).await;
+
+    let mut page = cache.take_or_new(id);
+    page.on_enter(router.clone(), state).await;
+    pages.push_back(page);
+
+    true
+}
+
 impl<S> Default for App<S>
 where
     S: Default,
@@ -175,3 +606,270 @@ where
         Self::stateful(S::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::future::{self, Future};
+
+    use ratatui::{Frame, backend::TestBackend};
+
+    use super::*;
+    use crate::{filter::EventFilter, signal::Signal};
+
+    type Log = Vec<(u8, &'static str)>;
+
+    // Page 7 takes every key event, page 8 refuses to be left and page 9 redirects to page 0.
+    #[derive(Default)]
+    struct TestPage {
+        id: u8,
+    }
+
+    impl PageState<Log> for TestPage {
+        type ID = u8;
+
+        fn new(id: u8) -> Self {
+            TestPage { id }
+        }
+
+        fn id(&self) -> u8 {
+            self.id
+        }
+
+        fn event_filter(&self) -> Option<EventFilter> {
+            (self.id == 7).then_some(EventFilter::All)
+        }
+
+        fn draw(&mut self, _frame: &mut Frame, _state: &Log) {}
+
+        fn on_event(&mut self, _: Event, _: Router<u8>, _: &mut Log) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+
+        fn on_enter(&mut self, _: Router<u8>, log: &mut Log) -> impl Future<Output = ()> {
+            log.push((self.id, "enter"));
+            future::ready(())
+        }
+
+        fn on_exit(&mut self, _: Router<u8>, log: &mut Log) -> impl Future<Output = ()> {
+            log.push((self.id, "exit"));
+            future::ready(())
+        }
+
+        fn on_pause(&mut self, _: Router<u8>, log: &mut Log) -> impl Future<Output = ()> {
+            log.push((self.id, "pause"));
+            future::ready(())
+        }
+
+        fn on_resume(&mut self, _: Router<u8>, log: &mut Log) -> impl Future<Output = ()> {
+            log.push((self.id, "resume"));
+            future::ready(())
+        }
+
+        fn task(&mut self, _: Router<u8>, _: &mut Log) -> impl Future<Output = ()> {
+            future::pending()
+        }
+
+        fn before_leave(
+            &mut self,
+            _: &RouterAction<u8>,
+            _: Router<u8>,
+            log: &mut Log,
+        ) -> impl Future<Output = Guard<u8>> {
+            log.push((self.id, "leave"));
+            future::ready(match self.id {
+                8 => Guard::Cancel,
+                9 => Guard::Redirect(0),
+                _ => Guard::Allow,
+            })
+        }
+
+        fn on_signal(&mut self, _: Signal, _: Router<u8>, _: &mut Log) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+    }
+
+    fn stack(ids: &[u8]) -> VecDeque<TestPage> {
+        ids.iter().map(|&id| TestPage::new(id)).collect()
+    }
+
+    fn ids(pages: &VecDeque<TestPage>) -> Vec<u8> {
+        pages.iter().map(|page| page.id).collect()
+    }
+
+    fn router() -> Router<u8> {
+        Router::channel().0
+    }
+
+    async fn launch_on(pages: &mut VecDeque<TestPage>, id: u8, mode: LaunchMode) -> (bool, Log) {
+        let mut cache = PageCache::new(0);
+        let mut log = Log::new();
+        let changed = launch(pages, &mut cache, &router(), &mut log, id, mode).await;
+        (changed, log)
+    }
+
+    #[tokio::test]
+    async fn single_top_skips_page_already_on_top() {
+        let mut pages = stack(&[0, 1]);
+
+        let (changed, log) = launch_on(&mut pages, 1, LaunchMode::SingleTop).await;
+
+        assert!(!changed);
+        assert!(log.is_empty());
+        assert_eq!(ids(&pages), [0, 1]);
+    }
+
+    #[tokio::test]
+    async fn single_top_pushes_when_page_is_below_top() {
+        let mut pages = stack(&[1, 2]);
+
+        let (changed, log) = launch_on(&mut pages, 1, LaunchMode::SingleTop).await;
+
+        assert!(changed);
+        assert_eq!(log, [(2, "pause"), (1, "enter")]);
+        assert_eq!(ids(&pages), [1, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn single_task_pops_back_to_existing_instance() {
+        let mut pages = stack(&[0, 1, 2, 3]);
+
+        let (changed, log) = launch_on(&mut pages, 1, LaunchMode::SingleTask).await;
+
+        assert!(changed);
+        assert_eq!(log, [(3, "exit"), (2, "exit"), (1, "resume")]);
+        assert_eq!(ids(&pages), [0, 1]);
+    }
+
+    #[tokio::test]
+    async fn single_task_on_top_is_a_no_op() {
+        let mut pages = stack(&[0, 1]);
+
+        let (changed, log) = launch_on(&mut pages, 1, LaunchMode::SingleTask).await;
+
+        assert!(!changed);
+        assert!(log.is_empty());
+        assert_eq!(ids(&pages), [0, 1]);
+    }
+
+    #[tokio::test]
+    async fn single_task_without_instance_pushes() {
+        let mut pages = stack(&[0, 1]);
+
+        let (changed, log) = launch_on(&mut pages, 2, LaunchMode::SingleTask).await;
+
+        assert!(changed);
+        assert_eq!(log, [(1, "pause"), (2, "enter")]);
+        assert_eq!(ids(&pages), [0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn standard_always_pushes() {
+        let mut pages = stack(&[0, 1]);
+
+        let (changed, log) = launch_on(&mut pages, 1, LaunchMode::Standard).await;
+
+        assert!(changed);
+        assert_eq!(log, [(1, "pause"), (1, "enter")]);
+        assert_eq!(ids(&pages), [0, 1, 1]);
+    }
+
+    async fn guard_on(
+        pages: &mut VecDeque<TestPage>,
+        action: RouterAction<u8>,
+    ) -> (Guard<u8>, Log) {
+        let mut log = Log::new();
+        let verdict = guard(pages, &action, &router(), &mut log).await;
+        (verdict, log)
+    }
+
+    fn asked(log: &Log) -> Vec<u8> {
+        log.iter().map(|&(id, _)| id).collect()
+    }
+
+    #[tokio::test]
+    async fn guard_asks_only_the_pages_being_left() {
+        let cases = [
+            (RouterAction::PUSH(5), vec![]),
+            (RouterAction::REDRAW, vec![]),
+            (RouterAction::BACK, vec![3]),
+            (RouterAction::REPLACE(5), vec![3]),
+            (RouterAction::CLEAR, vec![2, 1]),
+            (RouterAction::RESTART, vec![3, 2, 1]),
+            (RouterAction::EXIT, vec![3, 2, 1]),
+            (RouterAction::LAUNCH(1, LaunchMode::SingleTask), vec![3, 2]),
+            (RouterAction::LAUNCH(3, LaunchMode::SingleTask), vec![]),
+            (RouterAction::LAUNCH(1, LaunchMode::Standard), vec![]),
+            (RouterAction::LAUNCH(5, LaunchMode::SingleTask), vec![]),
+        ];
+
+        for (action, expected) in cases {
+            let (verdict, log) = guard_on(&mut stack(&[1, 2, 3]), action.clone()).await;
+            assert_eq!(verdict, Guard::Allow, "{action:?}");
+            assert_eq!(asked(&log), expected, "{action:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn guard_skips_back_on_root_page() {
+        let (verdict, log) = guard_on(&mut stack(&[8]), RouterAction::BACK).await;
+
+        assert_eq!(verdict, Guard::Allow);
+        assert!(log.is_empty());
+    }
+
+    #[tokio::test]
+    async fn guard_stops_at_first_cancel() {
+        let (verdict, log) = guard_on(&mut stack(&[1, 8, 2]), RouterAction::EXIT).await;
+
+        assert_eq!(verdict, Guard::Cancel);
+        assert_eq!(asked(&log), [2, 8]);
+    }
+
+    #[tokio::test]
+    async fn guard_returns_redirect() {
+        let (verdict, log) = guard_on(&mut stack(&[1, 9]), RouterAction::BACK).await;
+
+        assert_eq!(verdict, Guard::Redirect(0));
+        assert_eq!(asked(&log), [9]);
+    }
+
+    #[tokio::test]
+    async fn guard_single_task_asks_pages_above_instance() {
+        let action = RouterAction::LAUNCH(1, LaunchMode::SingleTask);
+        let (verdict, log) = guard_on(&mut stack(&[1, 8, 2]), action).await;
+
+        assert_eq!(verdict, Guard::Cancel);
+        assert_eq!(asked(&log), [2, 8]);
+    }
+
+    #[test]
+    fn park_cursor_moves_below_inline_viewport() {
+        let options = TerminalOptions {
+            viewport: Viewport::Inline(3),
+        };
+        let mut terminal = Terminal::with_options(TestBackend::new(10, 10), options).unwrap();
+        let area = terminal.get_frame().area();
+
+        park_cursor(&mut terminal).unwrap();
+
+        let cursor = terminal.backend_mut().get_cursor_position().unwrap();
+        assert_eq!(cursor.y, area.bottom());
+    }
+
+    #[test]
+    fn page_event_filter_wins_over_app_setting() {
+        let settings = Settings::<Log> {
+            event_filter: EventFilter::PressAndRepeat,
+            ..Settings::default()
+        };
+
+        assert_eq!(
+            settings.event_filter_for(&TestPage::new(1)),
+            EventFilter::PressAndRepeat
+        );
+        assert_eq!(
+            settings.event_filter_for(&TestPage::new(7)),
+            EventFilter::All
+        );
+    }
+}
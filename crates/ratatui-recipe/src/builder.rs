@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use ratatui::{Viewport, crossterm::event::KeyboardEnhancementFlags};
+
+use crate::{
+    app::App,
+    bus::EventBus,
+    filter::EventFilter,
+    history::History,
+    page::PageState,
+    store::{Reducer, Store},
+};
+
+const DEFAULT_KEEP_ALIVE: usize = 8;
+
+pub(crate) struct StateHooks<S> {
+    pub(crate) changed: fn(&mut S) -> bool,
+    pub(crate) undo: fn(&mut S) -> bool,
+    pub(crate) redo: fn(&mut S) -> bool,
+}
+
+impl<S> Default for StateHooks<S> {
+    fn default() -> Self {
+        StateHooks {
+            changed: |_| false,
+            undo: |_| false,
+            redo: |_| false,
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct TerminalFeatures {
+    pub(crate) mouse_capture: bool,
+    pub(crate) bracketed_paste: bool,
+    pub(crate) focus_change: bool,
+    pub(crate) keyboard_enhancement: Option<KeyboardEnhancementFlags>,
+}
+
+pub(crate) struct Settings<S> {
+    pub(crate) hooks: StateHooks<S>,
+    pub(crate) keep_alive: usize,
+    pub(crate) event_bus: EventBus,
+    pub(crate) event_filter: EventFilter,
+    pub(crate) tick_rate: Option<Duration>,
+    pub(crate) damage_tracking: bool,
+    pub(crate) viewport: Viewport,
+    pub(crate) terminal: TerminalFeatures,
+    pub(crate) handle_signals: bool,
+    pub(crate) suspend_on_ctrl_z: bool,
+}
+
+impl<S> Default for Settings<S> {
+    fn default() -> Self {
+        Settings {
+            hooks: StateHooks::default(),
+            keep_alive: DEFAULT_KEEP_ALIVE,
+            event_bus: EventBus::default(),
+            event_filter: EventFilter::default(),
+            tick_rate: None,
+            damage_tracking: false,
+            viewport: Viewport::Fullscreen,
+            terminal: TerminalFeatures::default(),
+            handle_signals: true,
+            suspend_on_ctrl_z: cfg!(unix),
+        }
+    }
+}
+
+impl<S> Settings<S> {
+    // A page's own filter wins over the app-wide one.
+    pub(crate) fn event_filter_for<P>(&self, page: &P) -> EventFilter
+    where
+        P: PageState<S>,
+    {
+        page.event_filter().unwrap_or(self.event_filter)
+    }
+}
+
+pub struct AppBuilder<S = ()> {
+    state: S,
+    settings: Settings<S>,
+}
+
+impl AppBuilder<()> {
+    pub fn new() -> Self {
+        Self::stateful(())
+    }
+}
+
+impl Default for AppBuilder<()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> AppBuilder<Store<S>>
+where
+    S: Reducer,
+{
+    pub fn store(state: S) -> Self {
+        Self::stateful(Store::new(state)).on_change(Store::take_changed)
+    }
+}
+
+impl<S> AppBuilder<History<S>> {
+    pub fn history(state: S, depth: usize) -> Self {
+        let mut builder = Self::stateful(History::new(state, depth));
+        builder.settings.hooks.undo = History::undo;
+        builder.settings.hooks.redo = History::redo;
+        builder
+    }
+}
+
+impl<S> AppBuilder<S> {
+    pub fn stateful(state: S) -> Self {
+        AppBuilder {
+            state,
+            settings: Settings::default(),
+        }
+    }
+
+    pub fn on_change(mut self, changed: fn(&mut S) -> bool) -> Self {
+        self.settings.hooks.changed = changed;
+        self
+    }
+
+    pub fn keep_alive(mut self, capacity: usize) -> Self {
+        self.settings.keep_alive = capacity;
+        self
+    }
+
+    pub fn event_bus(mut self, bus: EventBus) -> Self {
+        self.settings.event_bus = bus;
+        self
+    }
+
+    pub fn event_filter(mut self, filter: EventFilter) -> Self {
+        self.settings.event_filter = filter;
+        self
+    }
+
+    pub fn tick_rate(mut self, tick_rate: Duration) -> Self {
+        self.settings.tick_rate = Some(tick_rate);
+        self
+    }
+
+    pub fn damage_tracking(mut self, enabled: bool) -> Self {
+        self.settings.damage_tracking = enabled;
+        self
+    }
+
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.settings.viewport = viewport;
+        self
+    }
+
+    pub fn mouse_capture(mut self, enabled: bool) -> Self {
+        self.settings.terminal.mouse_capture = enabled;
+        self
+    }
+
+    pub fn bracketed_paste(mut self, enabled: bool) -> Self {
+        self.settings.terminal.bracketed_paste = enabled;
+        self
+    }
+
+    pub fn focus_change(mut self, enabled: bool) -> Self {
+        self.settings.terminal.focus_change = enabled;
+        self
+    }
+
+    pub fn keyboard_enhancement(mut self, flags: KeyboardEnhancementFlags) -> Self {
+        self.settings.terminal.keyboard_enhancement = Some(flags);
+        self
+    }
+
+    pub fn handle_signals(mut self, enabled: bool) -> Self {
+        self.settings.handle_signals = enabled;
+        self
+    }
+
+    pub fn suspend_on_ctrl_z(mut self, enabled: bool) -> Self {
+        self.settings.suspend_on_ctrl_z = enabled;
+        self
+    }
+
+    pub fn build(self) -> App<S> {
+        App::from_parts(self.state, self.settings)
+    }
+}
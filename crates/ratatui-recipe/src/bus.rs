@@ -0,0 +1,410 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+};
+
+use ratatui::crossterm::event::{Event, MouseEventKind};
+use tokio::{sync::Notify, task::JoinHandle};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EventBus {
+    #[default]
+    Unbounded,
+    Bounded(usize, Backpressure),
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Backpressure {
+    // Merges consecutive mouse moves, drags and resizes, then drops the oldest event when full.
+    #[default]
+    Coalesce,
+    DropOldest,
+    Block,
+}
+
+struct Queue {
+    events: VecDeque<Event>,
+    closed: bool,
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    not_full: Condvar,
+    ready: Notify,
+    bus: EventBus,
+}
+
+impl Shared {
+    fn new(bus: EventBus) -> Self {
+        Shared {
+            queue: Mutex::new(Queue {
+                events: VecDeque::new(),
+                closed: false,
+            }),
+            not_full: Condvar::new(),
+            ready: Notify::new(),
+            bus,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.queue.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    #[cfg(not(feature = "event-stream"))]
+    fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    fn push(&self, event: Event) -> bool {
+        matches!(self.enqueue(event, true), Offer::Sent)
+    }
+
+    fn enqueue(&self, event: Event, block: bool) -> Offer {
+        let mut queue = self.lock();
+
+        if queue.closed {
+            return Offer::Closed;
+        }
+
+        let coalesce = matches!(self.bus, EventBus::Bounded(_, Backpressure::Coalesce));
+
+        match queue.events.back_mut() {
+            Some(last) if coalesce && mergeable(last, &event) => *last = event,
+            _ => {
+                if let EventBus::Bounded(capacity, policy) = self.bus {
+                    while queue.events.len() >= capacity.max(1) {
+                        match policy {
+                            Backpressure::Coalesce | Backpressure::DropOldest => {
+                                queue.events.pop_front();
+                            }
+                            Backpressure::Block if !block => return Offer::Full(event),
+                            Backpressure::Block => {
+                                queue = self
+                                    .not_full
+                                    .wait(queue)
+                                    .unwrap_or_else(|err| err.into_inner());
+
+                                if queue.closed {
+                                    return Offer::Closed;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                queue.events.push_back(event);
+            }
+        }
+
+        drop(queue);
+        self.ready.notify_one();
+
+        Offer::Sent
+    }
+
+    fn pop(&self) -> Option<Event> {
+        let event = self.lock().events.pop_front();
+
+        if event.is_some() {
+            self.not_full.notify_one();
+        }
+
+        event
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.not_full.notify_all();
+    }
+}
+
+#[cfg_attr(not(feature = "event-stream"), allow(dead_code))]
+enum Offer {
+    Sent,
+    Full(Event),
+    Closed,
+}
+
+fn mergeable(last: &Event, next: &Event) -> bool {
+    match (last, next) {
+        (Event::Resize(_, _), Event::Resize(_, _)) => true,
+        (Event::Mouse(last), Event::Mouse(next)) => {
+            last.modifiers == next.modifiers
+                && match (last.kind, next.kind) {
+                    (MouseEventKind::Moved, MouseEventKind::Moved) => true,
+                    (MouseEventKind::Drag(last), MouseEventKind::Drag(next)) => last == next,
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+pub(crate) struct EventReceiver {
+    shared: Arc<Shared>,
+    reader: JoinHandle<()>,
+}
+
+impl EventReceiver {
+    pub(crate) fn spawn(bus: EventBus) -> Self {
+        let shared = Arc::new(Shared::new(bus));
+
+        let reader = read_events(shared.clone());
+
+        EventReceiver { shared, reader }
+    }
+
+    pub(crate) async fn close(&mut self) {
+        self.shutdown();
+
+        if !self.reader.is_finished() {
+            let _ = (&mut self.reader).await;
+        }
+    }
+
+    fn shutdown(&self) {
+        self.shared.close();
+        self.reader.abort();
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.shared.lock().events.is_empty()
+    }
+
+    pub(crate) async fn recv(&self) -> Event {
+        loop {
+            if let Some(event) = self.shared.pop() {
+                return event;
+            }
+
+            self.shared.ready.notified().await;
+        }
+    }
+}
+
+impl Drop for EventReceiver {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(not(feature = "event-stream"))]
+fn read_events(sender: Arc<Shared>) -> JoinHandle<()> {
+    use std::time::Duration;
+
+    use ratatui::crossterm::event;
+
+    // `event::read` can't be interrupted, so poll in short slices to notice shutdown.
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    tokio::task::spawn_blocking(move || {
+        while !sender.is_closed() {
+            match event::poll(POLL_INTERVAL) {
+                Ok(true) => {
+                    if let Ok(event) = event::read()
+                        && !sender.push(event)
+                    {
+                        break;
+                    }
+                }
+                Ok(false) => {}
+                Err(_) => break,
+            }
+        }
+    })
+}
+
+#[cfg(feature = "event-stream")]
+fn read_events(sender: Arc<Shared>) -> JoinHandle<()> {
+    use crossterm::event::EventStream;
+    use futures_util::StreamExt;
+
+    tokio::spawn(async move {
+        let mut stream = EventStream::new();
+
+        while let Some(Ok(event)) = stream.next().await {
+            match sender.enqueue(event, false) {
+                Offer::Sent => {}
+                Offer::Closed => break,
+                Offer::Full(event) => {
+                    let sender = sender.clone();
+                    let pushed = tokio::task::spawn_blocking(move || sender.push(event)).await;
+
+                    if !pushed.unwrap_or(false) {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use ratatui::crossterm::event::{
+        KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    };
+
+    use super::*;
+
+    fn key(c: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn drain(shared: &Shared) -> Vec<Event> {
+        std::iter::from_fn(|| shared.pop()).collect()
+    }
+
+    // Gives a blocked producer time to reach the condvar wait.
+    fn settle() {
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    #[test]
+    fn unbounded_keeps_everything() {
+        let shared = Shared::new(EventBus::Unbounded);
+
+        for _ in 0..3 {
+            assert!(shared.push(Event::Resize(80, 24)));
+        }
+
+        assert_eq!(drain(&shared).len(), 3);
+    }
+
+    #[test]
+    fn coalesce_merges_consecutive_moves_and_resizes() {
+        let shared = Shared::new(EventBus::Bounded(8, Backpressure::Coalesce));
+
+        shared.push(Event::Resize(80, 24));
+        shared.push(Event::Resize(100, 30));
+        shared.push(mouse(MouseEventKind::Moved, 1));
+        shared.push(mouse(MouseEventKind::Moved, 2));
+        shared.push(key('a'));
+        shared.push(mouse(MouseEventKind::Drag(MouseButton::Left), 3));
+        shared.push(mouse(MouseEventKind::Drag(MouseButton::Left), 4));
+        shared.push(mouse(MouseEventKind::Drag(MouseButton::Right), 5));
+
+        assert_eq!(
+            drain(&shared),
+            [
+                Event::Resize(100, 30),
+                mouse(MouseEventKind::Moved, 2),
+                key('a'),
+                mouse(MouseEventKind::Drag(MouseButton::Left), 4),
+                mouse(MouseEventKind::Drag(MouseButton::Right), 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn coalesce_never_merges_keys() {
+        let shared = Shared::new(EventBus::Bounded(8, Backpressure::Coalesce));
+
+        shared.push(key('a'));
+        shared.push(key('a'));
+
+        assert_eq!(drain(&shared), [key('a'), key('a')]);
+    }
+
+    #[test]
+    fn coalesce_drops_oldest_when_full() {
+        let shared = Shared::new(EventBus::Bounded(2, Backpressure::Coalesce));
+
+        for c in ['a', 'b', 'c'] {
+            assert!(shared.push(key(c)));
+        }
+
+        assert_eq!(drain(&shared), [key('b'), key('c')]);
+    }
+
+    #[test]
+    fn drop_oldest_keeps_newest_events() {
+        let shared = Shared::new(EventBus::Bounded(2, Backpressure::DropOldest));
+
+        for c in ['a', 'b', 'c', 'd'] {
+            assert!(shared.push(key(c)));
+        }
+        shared.push(Event::Resize(80, 24));
+        shared.push(Event::Resize(100, 30));
+
+        assert_eq!(
+            drain(&shared),
+            [Event::Resize(80, 24), Event::Resize(100, 30)]
+        );
+    }
+
+    #[test]
+    fn zero_capacity_holds_one_event() {
+        let shared = Shared::new(EventBus::Bounded(0, Backpressure::DropOldest));
+
+        shared.push(key('a'));
+        shared.push(key('b'));
+
+        assert_eq!(drain(&shared), [key('b')]);
+    }
+
+    #[test]
+    fn block_without_waiting_hands_the_event_back() {
+        let shared = Shared::new(EventBus::Bounded(1, Backpressure::Block));
+
+        assert!(matches!(shared.enqueue(key('a'), false), Offer::Sent));
+        assert!(matches!(shared.enqueue(key('b'), false), Offer::Full(event) if event == key('b')));
+        assert_eq!(drain(&shared), [key('a')]);
+    }
+
+    #[test]
+    fn block_waits_until_there_is_room() {
+        let shared = Arc::new(Shared::new(EventBus::Bounded(1, Backpressure::Block)));
+        shared.push(key('a'));
+
+        let producer = thread::spawn({
+            let shared = shared.clone();
+            move || shared.push(key('b'))
+        });
+
+        settle();
+        assert!(!producer.is_finished());
+
+        assert_eq!(shared.pop(), Some(key('a')));
+        assert!(producer.join().unwrap());
+        assert_eq!(drain(&shared), [key('b')]);
+    }
+
+    #[test]
+    fn close_releases_blocked_producer() {
+        let shared = Arc::new(Shared::new(EventBus::Bounded(1, Backpressure::Block)));
+        shared.push(key('a'));
+
+        let producer = thread::spawn({
+            let shared = shared.clone();
+            move || shared.push(key('b'))
+        });
+
+        settle();
+        shared.close();
+
+        assert!(!producer.join().unwrap());
+        assert_eq!(drain(&shared), [key('a')]);
+    }
+
+    #[test]
+    fn closed_queue_rejects_events() {
+        let shared = Shared::new(EventBus::Unbounded);
+        shared.close();
+
+        assert!(matches!(shared.enqueue(key('a'), true), Offer::Closed));
+        assert!(shared.pop().is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use crate::page::PageState;
+
+pub(crate) struct PageCache<P, S> {
+    pages: VecDeque<P>,
+    capacity: usize,
+    _state: PhantomData<fn(&mut S)>,
+}
+
+impl<P, S> PageCache<P, S>
+where
+    P: PageState<S>,
+{
+    pub(crate) fn new(capacity: usize) -> Self {
+        PageCache {
+            pages: VecDeque::new(),
+            capacity,
+            _state: PhantomData,
+        }
+    }
+
+    pub(crate) fn take_or_new(&mut self, id: P::ID) -> P {
+        match self.pages.iter().position(|page| page.id() == id) {
+            Some(index) => self.pages.remove(index).unwrap(),
+            None => P::new(id),
+        }
+    }
+
+    pub(crate) fn retire(&mut self, page: P) {
+        let id = page.id();
+
+        if !P::keep_alive(id) || self.capacity == 0 {
+            return;
+        }
+
+        self.pages.retain(|cached| cached.id() != id);
+
+        if self.pages.len() == self.capacity {
+            self.pages.pop_front();
+        }
+
+        self.pages.push_back(page);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::{self, Future};
+
+    use ratatui::{Frame, crossterm::event::Event};
+
+    use super::*;
+    use crate::{
+        router::{Guard, Router, RouterAction},
+        signal::Signal,
+    };
+
+    // ID 0 opts out of keep-alive.
+    #[derive(Default)]
+    struct TestPage {
+        id: u8,
+        visits: u32,
+    }
+
+    impl PageState for TestPage {
+        type ID = u8;
+
+        fn new(id: u8) -> Self {
+            TestPage { id, visits: 0 }
+        }
+
+        fn id(&self) -> u8 {
+            self.id
+        }
+
+        fn keep_alive(id: u8) -> bool {
+            id != 0
+        }
+
+        fn draw(&mut self, _frame: &mut Frame, _state: &()) {}
+
+        fn on_event(&mut self, _: Event, _: Router<u8>, _: &mut ()) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+
+        fn on_enter(&mut self, _: Router<u8>, _: &mut ()) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+
+        fn on_exit(&mut self, _: Router<u8>, _: &mut ()) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+
+        fn on_pause(&mut self, _: Router<u8>, _: &mut ()) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+
+        fn on_resume(&mut self, _: Router<u8>, _: &mut ()) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+
+        fn task(&mut self, _: Router<u8>, _: &mut ()) -> impl Future<Output = ()> {
+            future::pending()
+        }
+
+        fn before_leave(
+            &mut self,
+            _: &RouterAction<u8>,
+            _: Router<u8>,
+            _: &mut (),
+        ) -> impl Future<Output = Guard<u8>> {
+            future::ready(Guard::Allow)
+        }
+
+        fn on_signal(&mut self, _: Signal, _: Router<u8>, _: &mut ()) -> impl Future<Output = ()> {
+            future::ready(())
+        }
+    }
+
+    fn visited(id: u8, visits: u32) -> TestPage {
+        TestPage { id, visits }
+    }
+
+    #[test]
+    fn take_or_new_reuses_cached_page() {
+        let mut cache = PageCache::<TestPage, ()>::new(4);
+        cache.retire(visited(1, 3));
+
+        assert_eq!(cache.take_or_new(1).visits, 3);
+        assert_eq!(cache.take_or_new(1).visits, 0);
+        assert_eq!(cache.take_or_new(2).visits, 0);
+    }
+
+    #[test]
+    fn evicts_least_recently_retired_at_capacity() {
+        let mut cache = PageCache::<TestPage, ()>::new(2);
+        cache.retire(visited(1, 1));
+        cache.retire(visited(2, 1));
+        cache.retire(visited(3, 1));
+
+        assert_eq!(cache.take_or_new(1).visits, 0);
+        assert_eq!(cache.take_or_new(2).visits, 1);
+        assert_eq!(cache.take_or_new(3).visits, 1);
+    }
+
+    #[test]
+    fn retiring_again_replaces_and_refreshes_entry() {
+        let mut cache = PageCache::<TestPage, ()>::new(2);
+        cache.retire(visited(1, 1));
+        cache.retire(visited(2, 1));
+        cache.retire(visited(1, 2));
+        cache.retire(visited(3, 1));
+
+        assert_eq!(cache.take_or_new(1).visits, 2);
+        assert_eq!(cache.take_or_new(1).visits, 0);
+        assert_eq!(cache.take_or_new(2).visits, 0);
+        assert_eq!(cache.take_or_new(3).visits, 1);
+    }
+
+    #[test]
+    fn skips_pages_without_keep_alive() {
+        let mut cache = PageCache::<TestPage, ()>::new(2);
+        cache.retire(visited(0, 1));
+
+        assert_eq!(cache.take_or_new(0).visits, 0);
+    }
+
+    #[test]
+    fn zero_capacity_caches_nothing() {
+        let mut cache = PageCache::<TestPage, ()>::new(0);
+        cache.retire(visited(1, 1));
+
+        assert_eq!(cache.take_or_new(1).visits, 0);
+    }
+}
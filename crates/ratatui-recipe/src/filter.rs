@@ -0,0 +1,73 @@
+use ratatui::crossterm::event::{Event, KeyEventKind};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EventFilter {
+    // Windows and terminals with keyboard enhancement also report releases, so a single key
+    // press would otherwise reach `on_event` twice.
+    #[default]
+    Press,
+    PressAndRepeat,
+    All,
+}
+
+impl EventFilter {
+    pub fn allows(self, event: &Event) -> bool {
+        let Event::Key(key) = event else {
+            return true;
+        };
+
+        match self {
+            EventFilter::Press => key.kind == KeyEventKind::Press,
+            EventFilter::PressAndRepeat => key.kind != KeyEventKind::Release,
+            EventFilter::All => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    fn key(kind: KeyEventKind) -> Event {
+        Event::Key(KeyEvent::new_with_kind(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+            kind,
+        ))
+    }
+
+    fn allowed(filter: EventFilter) -> [bool; 3] {
+        [
+            KeyEventKind::Press,
+            KeyEventKind::Repeat,
+            KeyEventKind::Release,
+        ]
+        .map(|kind| filter.allows(&key(kind)))
+    }
+
+    #[test]
+    fn press_is_the_default() {
+        assert_eq!(EventFilter::default(), EventFilter::Press);
+    }
+
+    #[test]
+    fn modes_allow_key_kinds() {
+        assert_eq!(allowed(EventFilter::Press), [true, false, false]);
+        assert_eq!(allowed(EventFilter::PressAndRepeat), [true, true, false]);
+        assert_eq!(allowed(EventFilter::All), [true, true, true]);
+    }
+
+    #[test]
+    fn non_key_events_always_pass() {
+        for filter in [
+            EventFilter::Press,
+            EventFilter::PressAndRepeat,
+            EventFilter::All,
+        ] {
+            assert!(filter.allows(&Event::Resize(80, 24)));
+            assert!(filter.allows(&Event::FocusGained));
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::{collections::VecDeque, ops::Deref};
+
+pub struct History<S> {
+    state: S,
+    undo: VecDeque<S>,
+    redo: Vec<S>,
+    depth: usize,
+}
+
+impl<S> History<S> {
+    pub fn new(state: S, depth: usize) -> Self {
+        History {
+            state,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            depth,
+        }
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+
+        while self.undo.len() > depth {
+            self.undo.pop_front();
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop_back() {
+            Some(previous) => {
+                self.redo.push(std::mem::replace(&mut self.state, previous));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(next) => {
+                self.undo
+                    .push_back(std::mem::replace(&mut self.state, next));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+impl<S> History<S>
+where
+    S: Clone,
+{
+    pub fn snapshot(&mut self) {
+        if self.depth == 0 {
+            return;
+        }
+
+        if self.undo.len() == self.depth {
+            self.undo.pop_front();
+        }
+
+        self.undo.push_back(self.state.clone());
+        self.redo.clear();
+    }
+
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut S) -> R) -> R {
+        self.snapshot();
+        f(&mut self.state)
+    }
+}
+
+impl<S> Deref for History<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_and_redo_walk_the_snapshots() {
+        let mut history = History::new(0, 8);
+
+        history.update(|n| *n = 1);
+        history.update(|n| *n = 2);
+
+        assert!(history.undo());
+        assert_eq!(*history, 1);
+        assert!(history.undo());
+        assert_eq!(*history, 0);
+        assert!(!history.undo());
+        assert_eq!(*history, 0);
+
+        assert!(history.redo());
+        assert!(history.redo());
+        assert_eq!(*history, 2);
+        assert!(!history.redo());
+    }
+
+    #[test]
+    fn new_change_drops_redo_stack() {
+        let mut history = History::new(0, 8);
+
+        history.update(|n| *n = 1);
+        history.undo();
+        assert!(history.can_redo());
+
+        history.update(|n| *n = 5);
+        assert!(!history.can_redo());
+        assert!(!history.redo());
+        assert_eq!(*history, 5);
+    }
+
+    #[test]
+    fn depth_limits_undo_steps() {
+        let mut history = History::new(0, 2);
+
+        for n in 1..=4 {
+            history.update(|state| *state = n);
+        }
+
+        assert!(history.undo());
+        assert!(history.undo());
+        assert!(!history.undo());
+        assert_eq!(*history, 2);
+    }
+
+    #[test]
+    fn shrinking_depth_drops_oldest_snapshots() {
+        let mut history = History::new(0, 8);
+
+        for n in 1..=4 {
+            history.update(|state| *state = n);
+        }
+        history.set_depth(1);
+
+        assert!(history.undo());
+        assert_eq!(*history, 3);
+        assert!(!history.undo());
+    }
+
+    #[test]
+    fn zero_depth_keeps_no_history() {
+        let mut history = History::new(0, 0);
+
+        history.update(|n| *n = 1);
+
+        assert!(!history.can_undo());
+        assert!(!history.undo());
+        assert_eq!(*history, 1);
+    }
+}
@@ -1,9 +1,33 @@
 mod app;
+mod builder;
+mod bus;
+mod cache;
+mod filter;
+mod history;
+mod model;
 mod page;
+mod redraw;
 mod router;
+mod signal;
+mod store;
+mod transition;
 
 pub use app::App;
+pub use builder::AppBuilder;
+pub use bus::{Backpressure, EventBus};
+pub use filter::EventFilter;
+pub use history::History;
+pub use model::{Command, Model, ModelPage};
 pub use page::{Page, PageState, StatefulPage};
-pub use router::Router;
+#[doc(hidden)]
+pub use page::{PageParam, page_param};
+pub use router::{Guard, LaunchMode, Middleware, Router, RouterAction, Routes};
+pub use signal::Signal;
+pub use store::{Reducer, Store, Subscription};
+pub use transition::Transition;
 
-pub use ratatui_recipe_macros::Pages;
+pub use ratatui_recipe_macros::{Pages, keys, page_impl};
+
+// Lets the derives expand inside this crate's own tests.
+#[cfg(test)]
+extern crate self as ratatui_recipe;
@@ -0,0 +1,238 @@
+use std::{
+    collections::VecDeque,
+    future::{self, Future},
+    pin::Pin,
+};
+
+use ratatui::{Frame, crossterm::event::Event};
+use tokio::task::JoinSet;
+
+use crate::{
+    page::Page,
+    router::{Router, RouterAction},
+};
+
+pub enum Command<Msg, ID> {
+    None,
+    Message(Msg),
+    Perform(Pin<Box<dyn Future<Output = Msg> + Send>>),
+    Navigate(RouterAction<ID>),
+    Batch(Vec<Command<Msg, ID>>),
+}
+
+impl<Msg, ID> Command<Msg, ID> {
+    pub fn none() -> Self {
+        Command::None
+    }
+
+    pub fn message(msg: Msg) -> Self {
+        Command::Message(msg)
+    }
+
+    pub fn perform<F>(future: F) -> Self
+    where
+        F: Future<Output = Msg> + Send + 'static,
+    {
+        Command::Perform(Box::pin(future))
+    }
+
+    pub fn navigate(action: RouterAction<ID>) -> Self {
+        Command::Navigate(action)
+    }
+
+    pub fn batch(commands: impl IntoIterator<Item = Self>) -> Self {
+        Command::Batch(commands.into_iter().collect())
+    }
+
+    pub fn is_none(&self) -> bool {
+        matches!(self, Command::None)
+    }
+}
+
+pub trait Model: Default + Send {
+    type ID: Send;
+    type Message: Send + 'static;
+
+    fn update(&mut self, msg: Self::Message) -> Command<Self::Message, Self::ID>;
+    fn view(&self, frame: &mut Frame);
+
+    fn init(&mut self) -> Command<Self::Message, Self::ID> {
+        Command::None
+    }
+
+    fn on_event(&self, _event: Event) -> Option<Self::Message> {
+        None
+    }
+}
+
+pub struct ModelPage<M>
+where
+    M: Model,
+{
+    model: M,
+    effects: JoinSet<M::Message>,
+}
+
+impl<M> ModelPage<M>
+where
+    M: Model,
+{
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    fn execute(&mut self, command: Command<M::Message, M::ID>, router: &Router<M::ID>) {
+        let mut queue = VecDeque::from([command]);
+        let mut updated = false;
+
+        while let Some(command) = queue.pop_front() {
+            match command {
+                Command::None => {}
+                Command::Message(msg) => {
+                    queue.push_back(self.model.update(msg));
+                    updated = true;
+                }
+                Command::Perform(future) => {
+                    self.effects.spawn(future);
+                }
+                Command::Navigate(action) => router.send(action),
+                Command::Batch(commands) => queue.extend(commands),
+            }
+        }
+
+        if updated {
+            router.redraw();
+        }
+    }
+}
+
+impl<M> Default for ModelPage<M>
+where
+    M: Model,
+{
+    fn default() -> Self {
+        ModelPage {
+            model: M::default(),
+            effects: JoinSet::new(),
+        }
+    }
+}
+
+impl<M> Page<M::ID> for ModelPage<M>
+where
+    M: Model,
+{
+    fn draw(&mut self, frame: &mut Frame) {
+        self.model.view(frame);
+    }
+
+    async fn on_event(&mut self, event: Event, router: Router<M::ID>) {
+        if let Some(msg) = self.model.on_event(event) {
+            self.execute(Command::Message(msg), &router);
+        }
+    }
+
+    async fn on_enter(&mut self, router: Router<M::ID>) {
+        let command = self.model.init();
+        self.execute(command, &router);
+    }
+
+    // Effects still in flight belong to the visit that started them.
+    async fn on_exit(&mut self, _router: Router<M::ID>) {
+        self.effects.abort_all();
+    }
+
+    async fn task(&mut self, router: Router<M::ID>) {
+        match self.effects.join_next().await {
+            Some(Ok(msg)) => self.execute(Command::Message(msg), &router),
+            Some(Err(_)) => {}
+            None => future::pending().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::{mpsc, oneshot};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Counter {
+        count: i32,
+    }
+
+    enum Msg {
+        Increment,
+        Decrement,
+        Reset,
+        Done,
+    }
+
+    impl Model for Counter {
+        type ID = ();
+        type Message = Msg;
+
+        fn update(&mut self, msg: Msg) -> Command<Msg, ()> {
+            match msg {
+                Msg::Increment => self.count += 1,
+                Msg::Decrement if self.count == 0 => return Command::navigate(RouterAction::BACK),
+                Msg::Decrement => self.count -= 1,
+                Msg::Reset => {
+                    return Command::batch([Msg::Decrement, Msg::Increment].map(Command::message));
+                }
+                Msg::Done => {}
+            }
+
+            Command::none()
+        }
+
+        fn view(&self, _frame: &mut Frame) {}
+    }
+
+    #[test]
+    fn update_is_a_pure_function_of_model_and_message() {
+        let mut counter = Counter::default();
+
+        assert!(counter.update(Msg::Increment).is_none());
+        assert!(counter.update(Msg::Increment).is_none());
+        assert_eq!(counter.count, 2);
+
+        assert!(counter.update(Msg::Decrement).is_none());
+        assert_eq!(counter.count, 1);
+
+        assert!(
+            matches!(counter.update(Msg::Reset), Command::Batch(commands) if commands.len() == 2)
+        );
+        assert_eq!(counter.count, 1);
+
+        counter.count = 0;
+        assert!(matches!(
+            counter.update(Msg::Decrement),
+            Command::Navigate(RouterAction::BACK)
+        ));
+        assert_eq!(counter.count, 0);
+    }
+
+    #[tokio::test]
+    async fn effects_are_cancelled_on_exit() {
+        let (bus_tx, _bus_rx) = mpsc::unbounded_channel();
+        let (suspend_tx, _suspend_rx) = mpsc::unbounded_channel();
+        let router = Router::new(bus_tx, suspend_tx);
+
+        let (alive_tx, alive_rx) = oneshot::channel::<()>();
+        let mut page = ModelPage::<Counter>::default();
+        page.execute(
+            Command::perform(async move {
+                let _alive = alive_tx;
+                future::pending::<()>().await;
+                Msg::Done
+            }),
+            &router,
+        );
+
+        Page::on_exit(&mut page, router).await;
+
+        assert!(alive_rx.await.is_err());
+    }
+}
@@ -1,74 +1,391 @@
-use std::future;
+use std::{fmt::Debug, future, hash::Hash};
 
-use ratatui::{Frame, crossterm::event::Event};
+use ratatui::{Frame, crossterm::event::Event, layout::Rect};
 
-use crate::router::Router;
+use crate::{
+    filter::EventFilter,
+    router::{Guard, LaunchMode, Router, RouterAction},
+    signal::Signal,
+    transition::Transition,
+};
 
-pub trait PageState<S = ()>: Default {
-    type ID: Copy;
+pub trait PageState<S = ()>: Default + Send {
+    type ID: Copy + PartialEq + Send;
     fn new(id: Self::ID) -> Self;
+    fn id(&self) -> Self::ID;
+    fn keep_alive(_id: Self::ID) -> bool {
+        false
+    }
+    fn launch_mode(_id: Self::ID) -> LaunchMode {
+        LaunchMode::Standard
+    }
+    fn transition(_id: Self::ID) -> Transition {
+        Transition::None
+    }
+    fn event_filter(&self) -> Option<EventFilter> {
+        None
+    }
     fn draw(&mut self, frame: &mut Frame, state: &S);
-    async fn on_event(&mut self, event: Event, router: Router<Self::ID>, state: &mut S);
-    async fn on_enter(&mut self, router: Router<Self::ID>, state: &mut S);
-    async fn on_exit(&mut self, router: Router<Self::ID>, state: &mut S);
-    async fn on_pause(&mut self, router: Router<Self::ID>, state: &mut S);
-    async fn on_resume(&mut self, router: Router<Self::ID>, state: &mut S);
-    async fn task(&mut self, router: Router<Self::ID>, state: &mut S);
+    fn draw_damaged(&mut self, frame: &mut Frame, _damage: &[Rect], state: &S) {
+        self.draw(frame, state);
+    }
+    fn on_event(
+        &mut self,
+        event: Event,
+        router: Router<Self::ID>,
+        state: &mut S,
+    ) -> impl Future<Output = ()> + Send;
+    fn on_enter(
+        &mut self,
+        router: Router<Self::ID>,
+        state: &mut S,
+    ) -> impl Future<Output = ()> + Send;
+    fn on_exit(
+        &mut self,
+        router: Router<Self::ID>,
+        state: &mut S,
+    ) -> impl Future<Output = ()> + Send;
+    fn on_pause(
+        &mut self,
+        router: Router<Self::ID>,
+        state: &mut S,
+    ) -> impl Future<Output = ()> + Send;
+    fn on_resume(
+        &mut self,
+        router: Router<Self::ID>,
+        state: &mut S,
+    ) -> impl Future<Output = ()> + Send;
+    fn task(&mut self, router: Router<Self::ID>, state: &mut S) -> impl Future<Output = ()> + Send;
+    fn before_leave(
+        &mut self,
+        action: &RouterAction<Self::ID>,
+        router: Router<Self::ID>,
+        state: &mut S,
+    ) -> impl Future<Output = Guard<Self::ID>> + Send;
+    fn on_signal(
+        &mut self,
+        signal: Signal,
+        router: Router<Self::ID>,
+        state: &mut S,
+    ) -> impl Future<Output = ()> + Send;
 }
 
-pub trait Page<ID>: Default {
+pub trait Page<ID>: Default + Send
+where
+    ID: Send,
+{
     fn draw(&mut self, frame: &mut Frame);
-    async fn on_event(&mut self, event: Event, router: Router<ID>) {}
-    async fn on_enter(&mut self, router: Router<ID>) {}
-    async fn on_exit(&mut self, router: Router<ID>) {}
-    async fn on_pause(&mut self, router: Router<ID>) {}
-    async fn on_resume(&mut self, router: Router<ID>) {}
-    async fn task(&mut self, router: Router<ID>) {
-        future::pending().await
+    // With damage tracking on, only the regions passed to `Router::damage` are taken from this
+    // frame; the rest keeps the previous one. Drawing everything is always correct.
+    fn draw_damaged(&mut self, frame: &mut Frame, _damage: &[Rect]) {
+        self.draw(frame);
+    }
+    fn event_filter(&self) -> Option<EventFilter> {
+        None
+    }
+    fn on_event(&mut self, _event: Event, _router: Router<ID>) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_enter(&mut self, _router: Router<ID>) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_exit(&mut self, _router: Router<ID>) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_pause(&mut self, _router: Router<ID>) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_resume(&mut self, _router: Router<ID>) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn task(&mut self, _router: Router<ID>) -> impl Future<Output = ()> + Send {
+        future::pending()
+    }
+    fn before_leave(
+        &mut self,
+        _action: &RouterAction<ID>,
+        _router: Router<ID>,
+    ) -> impl Future<Output = Guard<ID>> + Send {
+        future::ready(Guard::Allow)
+    }
+    fn on_signal(
+        &mut self,
+        _signal: Signal,
+        router: Router<ID>,
+    ) -> impl Future<Output = ()> + Send {
+        router.unguarded().exit();
+        future::ready(())
     }
 }
 
-pub trait StatefulPage<ID, State>: Default {
+pub trait StatefulPage<ID, State>: Default + Send
+where
+    ID: Send,
+{
     fn draw(&mut self, frame: &mut Frame, state: &State);
-    async fn on_event(&mut self, event: Event, router: Router<ID>, state: &mut State) {}
-    async fn on_enter(&mut self, router: Router<ID>, state: &mut State) {}
-    async fn on_exit(&mut self, router: Router<ID>, state: &mut State) {}
-    async fn on_pause(&mut self, router: Router<ID>, state: &mut State) {}
-    async fn on_resume(&mut self, router: Router<ID>, state: &mut State) {}
-    async fn task(&mut self, router: Router<ID>, state: &mut State) {
-        future::pending().await
+    fn draw_damaged(&mut self, frame: &mut Frame, _damage: &[Rect], state: &State) {
+        self.draw(frame, state);
+    }
+    fn event_filter(&self) -> Option<EventFilter> {
+        None
+    }
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _router: Router<ID>,
+        _state: &mut State,
+    ) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_enter(
+        &mut self,
+        _router: Router<ID>,
+        _state: &mut State,
+    ) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_exit(
+        &mut self,
+        _router: Router<ID>,
+        _state: &mut State,
+    ) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_pause(
+        &mut self,
+        _router: Router<ID>,
+        _state: &mut State,
+    ) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn on_resume(
+        &mut self,
+        _router: Router<ID>,
+        _state: &mut State,
+    ) -> impl Future<Output = ()> + Send {
+        future::ready(())
+    }
+    fn task(&mut self, _router: Router<ID>, _state: &mut State) -> impl Future<Output = ()> + Send {
+        future::pending()
+    }
+    fn before_leave(
+        &mut self,
+        _action: &RouterAction<ID>,
+        _router: Router<ID>,
+        _state: &mut State,
+    ) -> impl Future<Output = Guard<ID>> + Send {
+        future::ready(Guard::Allow)
+    }
+    fn on_signal(
+        &mut self,
+        _signal: Signal,
+        router: Router<ID>,
+        _state: &mut State,
+    ) -> impl Future<Output = ()> + Send {
+        router.unguarded().exit();
+        future::ready(())
     }
 }
 
+// Fields of inline `Pages` variants are copied into the page ID. The derive copies them through
+// this trait so a field that doesn't fit is reported at the field rather than in generated code.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be a field of an inline page",
+    label = "inline page fields are copied into the page ID",
+    note = "fields of inline pages must be `Copy`, `Eq`, `Hash` and `Debug`"
+)]
+pub trait PageParam: Copy + Eq + Hash + Debug {}
+
+impl<T> PageParam for T where T: Copy + Eq + Hash + Debug {}
+
+#[doc(hidden)]
+pub fn page_param<T: PageParam>(value: &T) -> T {
+    *value
+}
+
+// Forwarding returns the page's own future, so the state reference is never held across awaits.
 impl<ID, S, P> StatefulPage<ID, S> for P
 where
+    ID: Send,
     P: Page<ID>,
 {
     fn draw(&mut self, frame: &mut Frame, _state: &S) {
         self.draw(frame);
     }
 
-    async fn on_event(&mut self, event: Event, router: Router<ID>, _state: &mut S) {
-        self.on_event(event, router).await;
+    fn draw_damaged(&mut self, frame: &mut Frame, damage: &[Rect], _state: &S) {
+        self.draw_damaged(frame, damage);
+    }
+
+    fn event_filter(&self) -> Option<EventFilter> {
+        self.event_filter()
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        router: Router<ID>,
+        _state: &mut S,
+    ) -> impl Future<Output = ()> + Send {
+        self.on_event(event, router)
+    }
+
+    fn on_enter(&mut self, router: Router<ID>, _state: &mut S) -> impl Future<Output = ()> + Send {
+        self.on_enter(router)
+    }
+
+    fn on_exit(&mut self, router: Router<ID>, _state: &mut S) -> impl Future<Output = ()> + Send {
+        self.on_exit(router)
+    }
+
+    fn on_pause(&mut self, router: Router<ID>, _state: &mut S) -> impl Future<Output = ()> + Send {
+        self.on_pause(router)
     }
 
-    async fn on_enter(&mut self, router: Router<ID>, _state: &mut S) {
-        self.on_enter(router).await;
+    fn on_resume(&mut self, router: Router<ID>, _state: &mut S) -> impl Future<Output = ()> + Send {
+        self.on_resume(router)
     }
 
-    async fn on_exit(&mut self, router: Router<ID>, _state: &mut S) {
-        self.on_exit(router).await;
+    fn task(&mut self, router: Router<ID>, _state: &mut S) -> impl Future<Output = ()> + Send {
+        self.task(router)
     }
 
-    async fn on_pause(&mut self, router: Router<ID>, _state: &mut S) {
-        self.on_pause(router).await;
+    fn before_leave(
+        &mut self,
+        action: &RouterAction<ID>,
+        router: Router<ID>,
+        _state: &mut S,
+    ) -> impl Future<Output = Guard<ID>> + Send {
+        self.before_leave(action, router)
     }
 
-    async fn on_resume(&mut self, router: Router<ID>, _state: &mut S) {
-        self.on_resume(router).await;
+    fn on_signal(
+        &mut self,
+        signal: Signal,
+        router: Router<ID>,
+        _state: &mut S,
+    ) -> impl Future<Output = ()> + Send {
+        self.on_signal(signal, router)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::{Pages, page_impl};
+
+    static PAGE_ENTERS: AtomicU32 = AtomicU32::new(0);
+    static OVERRIDE_ENTERS: AtomicU32 = AtomicU32::new(0);
+    static PROFILE_EXITS: AtomicU32 = AtomicU32::new(0);
+
+    #[derive(Pages)]
+    enum Tabs {
+        #[default]
+        #[page(on_enter = enter_home)]
+        Home(Screen),
+        Other(Screen),
+        #[page(render = profile, on_exit = exit_profile)]
+        Profile {
+            user: u32,
+        },
+    }
+
+    #[derive(Default)]
+    struct Screen;
+
+    impl Page<TabsID> for Screen {
+        fn draw(&mut self, _frame: &mut Frame) {}
+
+        async fn on_enter(&mut self, _router: Router<TabsID>) {
+            PAGE_ENTERS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    async fn enter_home<S>(_page: &mut Screen, _router: Router<TabsID>, _state: &mut S) {
+        assert_eq!(PAGE_ENTERS.load(Ordering::SeqCst), 1);
+        OVERRIDE_ENTERS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn profile(_frame: &mut Frame, _user: u32) {}
+
+    async fn exit_profile(_router: Router<TabsID>, user: u32) {
+        PROFILE_EXITS.fetch_add(user, Ordering::SeqCst);
+    }
+
+    fn router() -> Router<TabsID> {
+        let (bus_tx, _bus_rx) = mpsc::unbounded_channel();
+        let (suspend_tx, _suspend_rx) = mpsc::unbounded_channel();
+        Router::new(bus_tx, suspend_tx)
+    }
+
+    #[tokio::test]
+    async fn lifecycle_overrides_run_after_page_hooks() {
+        let router = router();
+
+        <Tabs as PageState>::new(TabsID::Home)
+            .on_enter(router.clone(), &mut ())
+            .await;
+        assert_eq!(PAGE_ENTERS.load(Ordering::SeqCst), 1);
+        assert_eq!(OVERRIDE_ENTERS.load(Ordering::SeqCst), 1);
+
+        <Tabs as PageState>::new(TabsID::Other)
+            .on_enter(router.clone(), &mut ())
+            .await;
+        assert_eq!(PAGE_ENTERS.load(Ordering::SeqCst), 2);
+        assert_eq!(OVERRIDE_ENTERS.load(Ordering::SeqCst), 1);
+
+        <Tabs as PageState>::new(TabsID::Profile { user: 7 })
+            .on_exit(router, &mut ())
+            .await;
+        assert_eq!(PROFILE_EXITS.load(Ordering::SeqCst), 7);
+    }
+
+    struct Visits {
+        entered: u32,
+        paused: u32,
+    }
+
+    #[page_impl(TabsID, state = u32)]
+    impl Visits {
+        #[init]
+        fn new() -> Self {
+            Visits {
+                entered: 0,
+                paused: 0,
+            }
+        }
+
+        #[draw]
+        fn render(&mut self, _frame: &mut Frame, _total: &u32) {}
+
+        #[on_enter]
+        fn entered(&mut self, _router: Router<TabsID>, total: &mut u32) {
+            self.entered += 1;
+            *total += 1;
+        }
+
+        #[on_pause]
+        async fn paused(&mut self, _router: Router<TabsID>, total: &mut u32) {
+            tokio::task::yield_now().await;
+            self.paused += 1;
+            *total += 10;
+        }
+    }
+
+    #[tokio::test]
+    async fn page_impl_calls_sync_and_async_hooks() {
+        let mut visits = Visits::default();
+        let mut total = 0;
+
+        StatefulPage::on_enter(&mut visits, router(), &mut total).await;
+        StatefulPage::on_pause(&mut visits, router(), &mut total).await;
+        StatefulPage::on_resume(&mut visits, router(), &mut total).await;
 
-    async fn task(&mut self, router: Router<ID>, _state: &mut S) {
-        self.task(router).await;
+        assert_eq!((visits.entered, visits.paused, total), (1, 1, 11));
     }
 }
@@ -0,0 +1,151 @@
+use std::{
+    mem,
+    time::{Duration, Instant},
+};
+
+use ratatui::{buffer::Buffer, layout::Rect};
+
+// Upper bound on how long queued input may hold back a frame.
+const MAX_FRAME_DELAY: Duration = Duration::from_millis(50);
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Redraw {
+    Full,
+    Damage(Vec<Rect>),
+}
+
+// Collects redraw requests between frames so a burst of events and REDRAWs is drawn once.
+pub(crate) struct Redraws {
+    tracking: bool,
+    full: bool,
+    damage: Vec<Rect>,
+    last_draw: Instant,
+}
+
+impl Redraws {
+    pub(crate) fn new(tracking: bool) -> Self {
+        Redraws {
+            tracking,
+            full: true,
+            damage: Vec::new(),
+            last_draw: Instant::now(),
+        }
+    }
+
+    pub(crate) fn full(&mut self) {
+        self.full = true;
+    }
+
+    // Without damage tracking a dirty region still costs a full frame.
+    pub(crate) fn damage(&mut self, area: Rect) {
+        if self.tracking {
+            self.damage.push(area);
+        } else {
+            self.full = true;
+        }
+    }
+
+    pub(crate) fn take(&mut self, pending: bool) -> Option<Redraw> {
+        if !self.full && self.damage.is_empty() {
+            return None;
+        }
+
+        if pending && self.last_draw.elapsed() < MAX_FRAME_DELAY {
+            return None;
+        }
+
+        self.last_draw = Instant::now();
+        let damage = mem::take(&mut self.damage);
+
+        if mem::take(&mut self.full) {
+            Some(Redraw::Full)
+        } else {
+            Some(Redraw::Damage(damage))
+        }
+    }
+}
+
+// A damage frame only trusts the page inside the damaged regions.
+pub(crate) fn keep_undamaged(buffer: &mut Buffer, last_frame: &Buffer, damage: &[Rect]) {
+    for position in buffer.area.positions() {
+        if !damage.iter().any(|area| area.contains(position)) {
+            buffer[position] = last_frame[position].clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drawn(tracking: bool) -> Redraws {
+        let mut redraws = Redraws::new(tracking);
+        assert_eq!(redraws.take(false), Some(Redraw::Full));
+        redraws
+    }
+
+    #[test]
+    fn burst_of_redraws_draws_once() {
+        let mut redraws = drawn(false);
+
+        for _ in 0..5 {
+            redraws.full();
+            assert_eq!(redraws.take(true), None);
+        }
+
+        assert_eq!(redraws.take(false), Some(Redraw::Full));
+        assert_eq!(redraws.take(false), None);
+    }
+
+    #[test]
+    fn max_frame_delay_forces_a_frame() {
+        let mut redraws = drawn(false);
+        redraws.full();
+        redraws.last_draw -= MAX_FRAME_DELAY;
+
+        assert_eq!(redraws.take(true), Some(Redraw::Full));
+        assert_eq!(redraws.take(true), None);
+    }
+
+    #[test]
+    fn damage_is_collected_until_drawn() {
+        let mut redraws = drawn(true);
+        let areas = [Rect::new(0, 0, 4, 1), Rect::new(2, 3, 4, 1)];
+
+        for area in areas {
+            redraws.damage(area);
+            assert_eq!(redraws.take(true), None);
+        }
+
+        assert_eq!(redraws.take(false), Some(Redraw::Damage(areas.to_vec())));
+        assert_eq!(redraws.take(false), None);
+    }
+
+    #[test]
+    fn full_redraw_supersedes_damage() {
+        let mut redraws = drawn(true);
+        redraws.damage(Rect::new(0, 0, 4, 1));
+        redraws.full();
+
+        assert_eq!(redraws.take(false), Some(Redraw::Full));
+        assert_eq!(redraws.take(false), None);
+    }
+
+    #[test]
+    fn keep_undamaged_restores_cells_outside_damage() {
+        let last_frame = Buffer::with_lines(["abcd", "efgh"]);
+        let mut buffer = Buffer::with_lines(["ABCD", "EFGH"]);
+
+        keep_undamaged(&mut buffer, &last_frame, &[Rect::new(1, 0, 2, 1)]);
+
+        assert_eq!(buffer, Buffer::with_lines(["aBCd", "efgh"]));
+    }
+
+    #[test]
+    fn damage_without_tracking_draws_full_frame() {
+        let mut redraws = drawn(false);
+        redraws.damage(Rect::new(0, 0, 4, 1));
+
+        assert_eq!(redraws.take(false), Some(Redraw::Full));
+    }
+}
@@ -1,50 +1,232 @@
+use std::{future::Future, pin::Pin};
+
+use ratatui::layout::Rect;
 use tokio::sync::mpsc;
 
+use crate::transition::Transition;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RouterAction<ID> {
     PUSH(ID),
+    LAUNCH(ID, LaunchMode),
     REPLACE(ID),
     BACK,
     CLEAR,
     RESTART,
     EXIT,
     REDRAW,
+    DAMAGE(Rect),
+    UNDO,
+    REDO,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LaunchMode {
+    #[default]
+    Standard,
+    SingleTop,
+    SingleTask,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Guard<ID> {
+    Allow,
+    Cancel,
+    Redirect(ID),
+}
+
+pub type Middleware<ID, S> = fn(ID, &S) -> Guard<ID>;
+
+// Routing config is passed to `App::run_with`, so one app can run different page enums in turn.
+pub struct Routes<ID, S> {
+    pub(crate) initial_route: Option<ID>,
+    pub(crate) middleware: Vec<Middleware<ID, S>>,
+}
+
+impl<ID, S> Routes<ID, S> {
+    pub fn new() -> Self {
+        Routes {
+            initial_route: None,
+            middleware: Vec::new(),
+        }
+    }
+
+    pub fn initial_route(mut self, id: ID) -> Self {
+        self.initial_route = Some(id);
+        self
+    }
+
+    pub fn middleware(mut self, middleware: Middleware<ID, S>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+}
+
+impl<ID, S> Default for Routes<ID, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) struct Navigation<ID> {
+    pub(crate) action: RouterAction<ID>,
+    pub(crate) guarded: bool,
+    pub(crate) transition: Option<Transition>,
+}
+
+pub(crate) type Suspend = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
+enum Bus<ID> {
+    App(mpsc::UnboundedSender<Navigation<ID>>),
+    Channel(mpsc::UnboundedSender<RouterAction<ID>>),
+}
+
+impl<ID> Clone for Bus<ID> {
+    fn clone(&self) -> Self {
+        match self {
+            Bus::App(bus) => Bus::App(bus.clone()),
+            Bus::Channel(bus) => Bus::Channel(bus.clone()),
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct Router<ID> {
-    bus: mpsc::UnboundedSender<RouterAction<ID>>,
+    bus: Bus<ID>,
+    suspend_bus: mpsc::UnboundedSender<Suspend>,
+    guarded: bool,
+    transition: Option<Transition>,
 }
 
 impl<ID> Router<ID> {
-    pub fn new(bus: mpsc::UnboundedSender<RouterAction<ID>>) -> Self {
-        Router { bus }
+    pub(crate) fn new(
+        bus: mpsc::UnboundedSender<Navigation<ID>>,
+        suspend_bus: mpsc::UnboundedSender<Suspend>,
+    ) -> Self {
+        Router {
+            bus: Bus::App(bus),
+            suspend_bus,
+            guarded: true,
+            transition: None,
+        }
+    }
+
+    // A router detached from any app, so `on_event` handlers can be tested by reading back the
+    // actions they send. Suspend tasks are dropped.
+    pub fn channel() -> (Self, mpsc::UnboundedReceiver<RouterAction<ID>>) {
+        let (bus, receiver) = mpsc::unbounded_channel();
+        let (suspend_bus, _) = mpsc::unbounded_channel();
+
+        let router = Router {
+            bus: Bus::Channel(bus),
+            suspend_bus,
+            guarded: true,
+            transition: None,
+        };
+
+        (router, receiver)
+    }
+
+    pub fn unguarded(&self) -> Self {
+        Router {
+            bus: self.bus.clone(),
+            suspend_bus: self.suspend_bus.clone(),
+            guarded: false,
+            transition: self.transition,
+        }
+    }
+
+    pub fn with_transition(&self, transition: Transition) -> Self {
+        Router {
+            bus: self.bus.clone(),
+            suspend_bus: self.suspend_bus.clone(),
+            guarded: self.guarded,
+            transition: Some(transition),
+        }
+    }
+
+    pub fn suspend<F, Fut>(&self, task: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let _ = self.suspend_bus.send(Box::new(move || Box::pin(task())));
+    }
+
+    pub fn send(&self, action: RouterAction<ID>) {
+        let _ = match &self.bus {
+            Bus::App(bus) => bus
+                .send(Navigation {
+                    action,
+                    guarded: self.guarded,
+                    transition: self.transition,
+                })
+                .map_err(drop),
+            Bus::Channel(bus) => bus.send(action).map_err(drop),
+        };
     }
 
     pub fn push(&self, id: ID) {
-        let _ = self.bus.send(RouterAction::PUSH(id));
+        self.send(RouterAction::PUSH(id));
+    }
+
+    pub fn push_with(&self, id: ID, mode: LaunchMode) {
+        self.send(RouterAction::LAUNCH(id, mode));
     }
 
     pub fn replace(&self, id: ID) {
-        let _ = self.bus.send(RouterAction::REPLACE(id));
+        self.send(RouterAction::REPLACE(id));
     }
 
     pub fn back(&self) {
-        let _ = self.bus.send(RouterAction::BACK);
+        self.send(RouterAction::BACK);
     }
 
     pub fn clear(&self) {
-        let _ = self.bus.send(RouterAction::CLEAR);
+        self.send(RouterAction::CLEAR);
     }
 
     pub fn restart(&self) {
-        let _ = self.bus.send(RouterAction::RESTART);
+        self.send(RouterAction::RESTART);
     }
 
     pub fn exit(&self) {
-        let _ = self.bus.send(RouterAction::EXIT);
+        self.send(RouterAction::EXIT);
     }
 
     pub fn redraw(&self) {
-        let _ = self.bus.send(RouterAction::REDRAW);
+        self.send(RouterAction::REDRAW);
+    }
+
+    pub fn damage(&self, area: Rect) {
+        self.send(RouterAction::DAMAGE(area));
+    }
+
+    pub fn undo(&self) {
+        self.send(RouterAction::UNDO);
+    }
+
+    pub fn redo(&self) {
+        self.send(RouterAction::REDO);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_receives_sent_actions() {
+        let (router, mut actions) = Router::channel();
+
+        router.push(1);
+        router.with_transition(Transition::Fade).back();
+        router.unguarded().exit();
+
+        assert_eq!(actions.try_recv(), Ok(RouterAction::PUSH(1)));
+        assert_eq!(actions.try_recv(), Ok(RouterAction::BACK));
+        assert_eq!(actions.try_recv(), Ok(RouterAction::EXIT));
+        assert!(actions.try_recv().is_err());
     }
 }
@@ -0,0 +1,246 @@
+use std::{future, io::Result};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Signal {
+    Interrupt,
+    Terminate,
+    Hangup,
+}
+
+pub(crate) enum Received {
+    Signal(Signal),
+    #[cfg(unix)]
+    Suspend,
+}
+
+pub(crate) struct Signals {
+    #[cfg(unix)]
+    listeners: Option<[(Signal, tokio::signal::unix::Signal); 3]>,
+    #[cfg(unix)]
+    suspend: Option<tokio::signal::unix::Signal>,
+    #[cfg(unix)]
+    installed: Vec<(libc::c_int, libc::sigaction)>,
+    #[cfg(not(unix))]
+    enabled: bool,
+}
+
+impl Signals {
+    #[cfg(unix)]
+    pub(crate) fn listen(enabled: bool, suspend: bool) -> Result<Self> {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut installed = Vec::new();
+        let mut listen = |raw| {
+            let previous = handlers::current(raw);
+            let listener = signal(SignalKind::from_raw(raw))?;
+            handlers::reinstall(raw);
+            installed.push((raw, previous));
+            Result::Ok(listener)
+        };
+
+        let listeners = if enabled {
+            Some([
+                (Signal::Interrupt, listen(libc::SIGINT)?),
+                (Signal::Terminate, listen(libc::SIGTERM)?),
+                (Signal::Hangup, listen(libc::SIGHUP)?),
+            ])
+        } else {
+            None
+        };
+
+        let suspend = if suspend {
+            Some(listen(libc::SIGTSTP)?)
+        } else {
+            None
+        };
+
+        Ok(Signals {
+            listeners,
+            suspend,
+            installed,
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn listen(enabled: bool, _suspend: bool) -> Result<Self> {
+        Ok(Signals { enabled })
+    }
+
+    #[cfg(unix)]
+    pub(crate) async fn recv(&mut self) -> Received {
+        let Signals {
+            listeners, suspend, ..
+        } = self;
+
+        let signal = async {
+            let Some([interrupt, terminate, hangup]) = listeners else {
+                return future::pending().await;
+            };
+
+            tokio::select! {
+                Some(()) = interrupt.1.recv() => interrupt.0,
+                Some(()) = terminate.1.recv() => terminate.0,
+                Some(()) = hangup.1.recv() => hangup.0,
+                else => future::pending().await,
+            }
+        };
+
+        let suspend = async {
+            let Some(suspend) = suspend else {
+                return future::pending().await;
+            };
+
+            if suspend.recv().await.is_none() {
+                future::pending().await
+            }
+        };
+
+        tokio::select! {
+            signal = signal => Received::Signal(signal),
+            () = suspend => Received::Suspend,
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) async fn recv(&mut self) -> Received {
+        if self.enabled && tokio::signal::ctrl_c().await.is_ok() {
+            return Received::Signal(Signal::Interrupt);
+        }
+
+        future::pending().await
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Signals {
+    fn drop(&mut self) {
+        for (raw, previous) in &self.installed {
+            handlers::restore(*raw, previous);
+        }
+    }
+}
+
+// Our own SIGTSTP handler would swallow the raise, so stop under the default action and put the
+// handler back once the shell resumes us.
+#[cfg(unix)]
+pub(crate) fn stop() {
+    let previous = handlers::set_default(libc::SIGTSTP);
+
+    unsafe {
+        libc::raise(libc::SIGTSTP);
+    }
+
+    handlers::set(libc::SIGTSTP, &previous);
+}
+
+// tokio installs a handler once per signal and never removes it, which would leave Ctrl+C and
+// `kill` ignored once the app returns. Between runs whatever was in place before the run is put
+// back (SIG_IGN under nohup, or the host's own tokio handler) and tokio's handler is stashed until
+// the next run listens again. A tokio listener first created after a run is not reached until then.
+#[cfg(unix)]
+mod handlers {
+    use std::{
+        mem, ptr,
+        sync::{Mutex, MutexGuard},
+    };
+
+    use libc::{c_int, sigaction};
+
+    static STASHED: Mutex<Vec<(c_int, sigaction)>> = Mutex::new(Vec::new());
+
+    fn stashed() -> MutexGuard<'static, Vec<(c_int, sigaction)>> {
+        STASHED.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    pub(super) fn set(signal: c_int, action: &sigaction) -> sigaction {
+        unsafe {
+            let mut previous: sigaction = mem::zeroed();
+            libc::sigaction(signal, action, &mut previous);
+            previous
+        }
+    }
+
+    pub(super) fn set_default(signal: c_int) -> sigaction {
+        let mut default: sigaction = unsafe { mem::zeroed() };
+        default.sa_sigaction = libc::SIG_DFL;
+        set(signal, &default)
+    }
+
+    pub(super) fn current(signal: c_int) -> sigaction {
+        unsafe {
+            let mut current: sigaction = mem::zeroed();
+            libc::sigaction(signal, ptr::null(), &mut current);
+            current
+        }
+    }
+
+    pub(super) fn restore(signal: c_int, action: &sigaction) {
+        let previous = set(signal, action);
+        let mut stashed = stashed();
+        stashed.retain(|(stashed, _)| *stashed != signal);
+        stashed.push((signal, previous));
+    }
+
+    pub(super) fn reinstall(signal: c_int) {
+        let mut stashed = stashed();
+
+        if let Some(index) = stashed.iter().position(|(stashed, _)| *stashed == signal) {
+            let (_, action) = stashed.remove(index);
+            set(signal, &action);
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::signal::unix::{SignalKind, signal};
+
+    use super::*;
+
+    fn handler(signal: libc::c_int) -> libc::sighandler_t {
+        handlers::current(signal).sa_sigaction
+    }
+
+    fn ignore(signal: libc::c_int) {
+        let mut ignore: libc::sigaction = unsafe { std::mem::zeroed() };
+        ignore.sa_sigaction = libc::SIG_IGN;
+        handlers::set(signal, &ignore);
+    }
+
+    // One test, because the dispositions are process-wide.
+    #[tokio::test]
+    async fn previous_handlers_are_restored_between_runs() {
+        let signals = [libc::SIGINT, libc::SIGTERM, libc::SIGHUP, libc::SIGTSTP];
+
+        // A host already waiting on Ctrl+C, started under nohup.
+        let mut host = signal(SignalKind::interrupt()).unwrap();
+        ignore(libc::SIGHUP);
+        let before = signals.map(handler);
+
+        for _ in 0..2 {
+            let mut listening = Signals::listen(true, true).unwrap();
+            assert_ne!(handler(libc::SIGHUP), libc::SIG_IGN);
+
+            unsafe {
+                libc::raise(libc::SIGHUP);
+            }
+            assert!(matches!(
+                listening.recv().await,
+                Received::Signal(Signal::Hangup)
+            ));
+
+            drop(listening);
+            assert_eq!(signals.map(handler), before);
+        }
+
+        unsafe {
+            libc::raise(libc::SIGINT);
+        }
+        let received = tokio::time::timeout(Duration::from_secs(1), host.recv()).await;
+        assert_eq!(received, Ok(Some(())));
+
+        handlers::set_default(libc::SIGHUP);
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    ops::Deref,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+pub trait Reducer {
+    type Action;
+    fn reduce(&mut self, action: Self::Action);
+}
+
+// Unsubscribes when dropped, so a page that keeps it in a field stops triggering redraws once the
+// page is gone, and re-subscribing on every `on_enter` replaces the old subscription. Keep-alive
+// pages outlive their exit and should drop it in `on_exit`.
+#[derive(Debug)]
+#[must_use = "dropping a Subscription unsubscribes it"]
+pub struct Subscription {
+    active: Arc<AtomicBool>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.active.store(false, Ordering::Relaxed);
+    }
+}
+
+type Selector<S> = Box<dyn FnMut(&S) -> bool + Send>;
+
+pub struct Store<S> {
+    state: S,
+    subscriptions: Vec<(Arc<AtomicBool>, Selector<S>)>,
+    changed: bool,
+}
+
+impl<S> Store<S> {
+    pub fn new(state: S) -> Self {
+        Store {
+            state,
+            subscriptions: Vec::new(),
+            changed: false,
+        }
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+
+    pub fn subscribe<T, F>(&mut self, selector: F) -> Subscription
+    where
+        F: Fn(&S) -> T + Send + 'static,
+        T: PartialEq + Send + 'static,
+    {
+        self.prune();
+
+        let active = Arc::new(AtomicBool::new(true));
+        let mut last = selector(&self.state);
+        self.subscriptions.push((
+            active.clone(),
+            Box::new(move |state| {
+                let next = selector(state);
+                let changed = next != last;
+                last = next;
+                changed
+            }),
+        ));
+
+        Subscription { active }
+    }
+
+    pub fn unsubscribe(&mut self, subscription: Subscription) {
+        drop(subscription);
+        self.prune();
+    }
+
+    fn prune(&mut self) {
+        self.subscriptions
+            .retain(|(active, _)| active.load(Ordering::Relaxed));
+    }
+
+    pub(crate) fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+}
+
+impl<S> Store<S>
+where
+    S: Reducer,
+{
+    pub fn dispatch(&mut self, action: S::Action) {
+        self.state.reduce(action);
+        self.prune();
+
+        // Every selector has to run so each one caches the latest slice.
+        for (_, changed) in &mut self.subscriptions {
+            if changed(&self.state) {
+                self.changed = true;
+            }
+        }
+    }
+}
+
+impl<S> Deref for Store<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S> Default for Store<S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Counters {
+        clicks: u32,
+        hovers: u32,
+    }
+
+    enum Action {
+        Click,
+        Hover,
+    }
+
+    impl Reducer for Counters {
+        type Action = Action;
+
+        fn reduce(&mut self, action: Action) {
+            match action {
+                Action::Click => self.clicks += 1,
+                Action::Hover => self.hovers += 1,
+            }
+        }
+    }
+
+    #[test]
+    fn dispatch_marks_changed_only_for_subscribed_slices() {
+        let mut store = Store::new(Counters::default());
+        let _clicks = store.subscribe(|state| state.clicks);
+
+        store.dispatch(Action::Hover);
+        assert!(!store.take_changed());
+        assert_eq!(store.hovers, 1);
+
+        store.dispatch(Action::Click);
+        assert!(store.take_changed());
+        assert!(!store.take_changed());
+    }
+
+    #[test]
+    fn unsubscribe_stops_redraws() {
+        let mut store = Store::new(Counters::default());
+        let clicks = store.subscribe(|state| state.clicks);
+
+        store.unsubscribe(clicks);
+        store.dispatch(Action::Click);
+
+        assert!(!store.take_changed());
+        assert!(store.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn dropping_subscription_unsubscribes() {
+        let mut store = Store::new(Counters::default());
+        drop(store.subscribe(|state| state.clicks));
+
+        store.dispatch(Action::Click);
+
+        assert!(!store.take_changed());
+        assert!(store.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn resubscribing_replaces_previous_subscription() {
+        let mut store = Store::new(Counters::default());
+        let mut page_subscription = store.subscribe(|state| state.clicks);
+
+        for _ in 0..3 {
+            page_subscription = store.subscribe(|state| state.clicks);
+        }
+        store.dispatch(Action::Click);
+
+        assert!(store.take_changed());
+        assert_eq!(store.subscriptions.len(), 1);
+        drop(page_subscription);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{io::Result, time::Duration};
+
+use ratatui::{
+    DefaultTerminal, Frame, Terminal, backend::TestBackend, buffer::Buffer, layout::Rect,
+    style::Modifier,
+};
+
+const FRAMES: u16 = 8;
+const FRAME_INTERVAL: Duration = Duration::from_millis(20);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Transition {
+    #[default]
+    None,
+    Slide,
+    Fade,
+    Wipe,
+}
+
+impl Transition {
+    pub(crate) async fn play(
+        self,
+        terminal: &mut DefaultTerminal,
+        from: &Buffer,
+        to: &Buffer,
+        forward: bool,
+    ) -> Result<()> {
+        for frame in 1..=FRAMES {
+            terminal.draw(|f| {
+                let area = f.area();
+                self.compose(from, to, frame, forward, f.buffer_mut(), area);
+            })?;
+
+            tokio::time::sleep(FRAME_INTERVAL).await;
+        }
+
+        Ok(())
+    }
+
+    fn compose(
+        self,
+        from: &Buffer,
+        to: &Buffer,
+        frame: u16,
+        forward: bool,
+        target: &mut Buffer,
+        area: Rect,
+    ) {
+        let width = area.width;
+        let offset = (u32::from(width) * u32::from(frame) / u32::from(FRAMES)) as u16;
+
+        for y in 0..area.height {
+            for x in 0..width {
+                let (source, sx, dim) = match self {
+                    Transition::None => (to, x, false),
+                    Transition::Slide if forward => {
+                        if x < width - offset {
+                            (from, x + offset, false)
+                        } else {
+                            (to, x - (width - offset), false)
+                        }
+                    }
+                    Transition::Slide => {
+                        if x < offset {
+                            (to, x + (width - offset), false)
+                        } else {
+                            (from, x - offset, false)
+                        }
+                    }
+                    Transition::Wipe => {
+                        let revealed = if forward {
+                            x < offset
+                        } else {
+                            x >= width - offset
+                        };
+
+                        (if revealed { to } else { from }, x, false)
+                    }
+                    Transition::Fade => {
+                        if frame * 2 <= FRAMES {
+                            (from, x, true)
+                        } else {
+                            (to, x, frame < FRAMES)
+                        }
+                    }
+                };
+
+                if let Some(cell) = source.cell((sx, y))
+                    && let Some(target) = target.cell_mut((area.x + x, area.y + y))
+                {
+                    *target = cell.clone();
+
+                    if dim {
+                        target.modifier.insert(Modifier::DIM);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn snapshot(area: Rect, draw: impl FnOnce(&mut Frame)) -> Buffer {
+    let Ok(mut terminal) = Terminal::new(TestBackend::new(area.width, area.height));
+    let Ok(completed) = terminal.draw(draw);
+
+    completed.buffer.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compose(transition: Transition, frame: u16, forward: bool) -> Buffer {
+        let from = Buffer::with_lines(["abcdefgh"]);
+        let to = Buffer::with_lines(["ABCDEFGH"]);
+        let mut target = Buffer::empty(from.area);
+
+        transition.compose(&from, &to, frame, forward, &mut target, from.area);
+        target
+    }
+
+    fn dimmed(line: &str) -> Buffer {
+        let mut buffer = Buffer::with_lines([line]);
+        buffer.set_style(buffer.area, Modifier::DIM);
+        buffer
+    }
+
+    #[test]
+    fn slide_moves_pages_in_direction_of_travel() {
+        let cases = [
+            (1, true, "bcdefghA"),
+            (FRAMES, true, "ABCDEFGH"),
+            (1, false, "Habcdefg"),
+            (FRAMES, false, "ABCDEFGH"),
+        ];
+
+        for (frame, forward, expected) in cases {
+            assert_eq!(
+                compose(Transition::Slide, frame, forward),
+                Buffer::with_lines([expected]),
+                "frame {frame}, forward {forward}"
+            );
+        }
+    }
+
+    #[test]
+    fn wipe_reveals_from_leading_edge() {
+        let cases = [
+            (1, true, "Abcdefgh"),
+            (FRAMES, true, "ABCDEFGH"),
+            (1, false, "abcdefgH"),
+            (FRAMES, false, "ABCDEFGH"),
+        ];
+
+        for (frame, forward, expected) in cases {
+            assert_eq!(
+                compose(Transition::Wipe, frame, forward),
+                Buffer::with_lines([expected]),
+                "frame {frame}, forward {forward}"
+            );
+        }
+    }
+
+    #[test]
+    fn fade_dims_through_to_incoming_page() {
+        for forward in [true, false] {
+            assert_eq!(compose(Transition::Fade, 1, forward), dimmed("abcdefgh"));
+            assert_eq!(
+                compose(Transition::Fade, FRAMES / 2 + 1, forward),
+                dimmed("ABCDEFGH")
+            );
+            assert_eq!(
+                compose(Transition::Fade, FRAMES, forward),
+                Buffer::with_lines(["ABCDEFGH"])
+            );
+        }
+    }
+}
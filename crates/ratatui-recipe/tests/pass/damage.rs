@@ -0,0 +1,63 @@
+use ratatui::{
+    Frame, Terminal,
+    backend::TestBackend,
+    layout::Rect,
+    widgets::{Paragraph, Widget},
+};
+use ratatui_recipe::{Page, PageState, Pages, Router, RouterAction};
+
+#[derive(Pages)]
+enum AppPages {
+    #[default]
+    Clock(ClockScreen),
+    #[page(render = about)]
+    About,
+}
+
+#[derive(Default)]
+struct ClockScreen;
+
+impl Page<AppPagesID> for ClockScreen {
+    fn draw(&mut self, frame: &mut Frame) {
+        frame.render_widget(Paragraph::new("clock 12:00"), frame.area());
+    }
+
+    fn draw_damaged(&mut self, frame: &mut Frame, damage: &[Rect]) {
+        for &area in damage {
+            Paragraph::new("12:01").render(area, frame.buffer_mut());
+        }
+    }
+}
+
+fn about(frame: &mut Frame) {
+    frame.render_widget(Paragraph::new("about"), frame.area());
+}
+
+fn draw_damaged(id: AppPagesID, damage: &[Rect]) -> String {
+    let mut page: AppPages = PageState::<()>::new(id);
+
+    let mut terminal = Terminal::new(TestBackend::new(12, 1)).unwrap();
+    terminal
+        .draw(|frame| PageState::draw_damaged(&mut page, frame, damage, &()))
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn main() {
+    let clock = Rect::new(6, 0, 5, 1);
+
+    assert_eq!(draw_damaged(AppPagesID::Clock, &[clock]), "      12:01");
+    assert_eq!(draw_damaged(AppPagesID::About, &[clock]), "about");
+
+    let (router, mut actions) = Router::<AppPagesID>::channel();
+    router.damage(clock);
+    assert_eq!(actions.try_recv(), Ok(RouterAction::DAMAGE(clock)));
+}
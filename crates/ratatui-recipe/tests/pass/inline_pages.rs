@@ -0,0 +1,61 @@
+use ratatui::{Frame, Terminal, backend::TestBackend, widgets::Paragraph};
+use ratatui_recipe::{Page, PageState, Pages};
+
+#[derive(Pages)]
+enum AppPages {
+    #[default]
+    Home(HomeScreen),
+    #[page(render = about)]
+    About,
+    #[page(render = profile)]
+    Profile { user: u32, admin: bool },
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<AppPagesID> for HomeScreen {
+    fn draw(&mut self, frame: &mut Frame) {
+        frame.render_widget(Paragraph::new("home"), frame.area());
+    }
+}
+
+fn about(frame: &mut Frame) {
+    frame.render_widget(Paragraph::new("about"), frame.area());
+}
+
+fn profile(frame: &mut Frame, user: u32, admin: bool) {
+    let text = format!("user {user} admin {admin}");
+    frame.render_widget(Paragraph::new(text), frame.area());
+}
+
+fn render(id: AppPagesID) -> String {
+    let mut page: AppPages = PageState::<()>::new(id);
+    assert_eq!(PageState::<()>::id(&page), id);
+
+    let mut terminal = Terminal::new(TestBackend::new(20, 1)).unwrap();
+    terminal
+        .draw(|frame| PageState::draw(&mut page, frame, &()))
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn main() {
+    assert_eq!(render(AppPagesID::Home), "home");
+    assert_eq!(render(AppPagesID::About), "about");
+    assert_eq!(
+        render(AppPagesID::Profile {
+            user: 7,
+            admin: true
+        }),
+        "user 7 admin true"
+    );
+}
@@ -0,0 +1,80 @@
+use ratatui::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
+};
+use ratatui_recipe::keys;
+
+fn fired(event: Event) -> &'static str {
+    let mut arm = "none";
+
+    keys!(event, {
+        ctrl+c => arm = "quit",
+        q | esc => arm = "close",
+        shift+tab => arm = "previous",
+        tab => arm = "next",
+        'A' => arm = "upper a",
+        a => arm = "lower a",
+        '?' => arm = "help",
+        alt+enter => arm = "fullscreen",
+        enter => arm = "confirm",
+        1 => arm = "first",
+        f5 => arm = "refresh",
+        _ => arm = "other",
+    });
+
+    arm
+}
+
+fn press(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+fn with_kind(code: KeyCode, modifiers: KeyModifiers, kind: KeyEventKind) -> Event {
+    Event::Key(KeyEvent::new_with_kind(code, modifiers, kind))
+}
+
+fn main() {
+    let none = KeyModifiers::NONE;
+    let shift = KeyModifiers::SHIFT;
+    let ctrl = KeyModifiers::CONTROL;
+
+    assert_eq!(fired(press(KeyCode::Char('c'), ctrl)), "quit");
+    assert_eq!(fired(press(KeyCode::Char('C'), ctrl | shift)), "other");
+    assert_eq!(fired(press(KeyCode::Char('c'), none)), "other");
+
+    assert_eq!(fired(press(KeyCode::Char('q'), none)), "close");
+    assert_eq!(fired(press(KeyCode::Esc, none)), "close");
+    assert_eq!(fired(press(KeyCode::Char('q'), ctrl)), "other");
+
+    // Terminals report shift+tab as BackTab, with or without the shift flag.
+    assert_eq!(fired(press(KeyCode::BackTab, shift)), "previous");
+    assert_eq!(fired(press(KeyCode::BackTab, none)), "previous");
+    assert_eq!(fired(press(KeyCode::Tab, none)), "next");
+
+    // Shift is implied by the character itself.
+    assert_eq!(fired(press(KeyCode::Char('A'), shift)), "upper a");
+    assert_eq!(fired(press(KeyCode::Char('A'), none)), "upper a");
+    assert_eq!(fired(press(KeyCode::Char('a'), none)), "lower a");
+    assert_eq!(fired(press(KeyCode::Char('?'), shift)), "help");
+    assert_eq!(fired(press(KeyCode::Char('?'), none)), "help");
+
+    assert_eq!(fired(press(KeyCode::Enter, KeyModifiers::ALT)), "fullscreen");
+    assert_eq!(fired(press(KeyCode::Enter, none)), "confirm");
+    assert_eq!(fired(press(KeyCode::Char('1'), none)), "first");
+    assert_eq!(fired(press(KeyCode::F(5), none)), "refresh");
+
+    // Only presses fire, so release and repeat events skip every arm and the fallback.
+    let release = KeyEventKind::Release;
+    assert_eq!(fired(with_kind(KeyCode::Char('c'), ctrl, release)), "none");
+    assert_eq!(fired(with_kind(KeyCode::Char('x'), none, release)), "none");
+    let repeat = KeyEventKind::Repeat;
+    assert_eq!(fired(with_kind(KeyCode::Char('q'), none, repeat)), "none");
+
+    let mouse = Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Moved,
+        column: 0,
+        row: 0,
+        modifiers: none,
+    });
+    assert_eq!(fired(mouse), "none");
+    assert_eq!(fired(Event::Resize(80, 24)), "none");
+}
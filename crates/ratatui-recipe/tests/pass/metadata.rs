@@ -0,0 +1,52 @@
+use ratatui::Frame;
+use ratatui_recipe::{Page, Pages};
+
+#[derive(Pages)]
+enum Tabs {
+    #[default]
+    #[page(title = "Home", icon = "⌂")]
+    Home(Screen),
+    #[page(title = "Settings", icon = "⚙")]
+    Settings(Screen),
+    #[page(hidden)]
+    Debug(Screen),
+    #[page(render = about)]
+    About,
+    #[page(render = profile)]
+    Profile { user: u32 },
+}
+
+#[derive(Default)]
+struct Screen;
+
+impl Page<TabsID> for Screen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+fn about(_frame: &mut Frame) {}
+
+fn profile(_frame: &mut Frame, _user: u32) {}
+
+fn main() {
+    assert_eq!(
+        TabsID::all(),
+        [TabsID::Home, TabsID::Settings, TabsID::Debug, TabsID::About]
+    );
+    assert_eq!(
+        TabsID::visible().collect::<Vec<_>>(),
+        [TabsID::Home, TabsID::Settings, TabsID::About]
+    );
+
+    assert_eq!(TabsID::Home.title(), "Home");
+    assert_eq!(TabsID::Settings.title(), "Settings");
+    assert_eq!(TabsID::Debug.title(), "Debug");
+    assert_eq!(TabsID::Profile { user: 7 }.title(), "Profile");
+
+    assert_eq!(TabsID::Home.icon(), Some("⌂"));
+    assert_eq!(TabsID::Settings.icon(), Some("⚙"));
+    assert_eq!(TabsID::About.icon(), None);
+
+    assert!(TabsID::Debug.is_hidden());
+    assert!(!TabsID::Home.is_hidden());
+    assert!(!TabsID::Profile { user: 7 }.is_hidden());
+}
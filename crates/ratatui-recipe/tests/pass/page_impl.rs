@@ -0,0 +1,105 @@
+use ratatui::{Frame, Terminal, backend::TestBackend, crossterm::event::Event, widgets::Paragraph};
+use ratatui_recipe::{PageState, Pages, Router, page_impl};
+
+#[derive(Default)]
+struct Counter {
+    count: u32,
+}
+
+#[derive(Pages)]
+#[pages(state = Counter)]
+enum AppPages {
+    #[default]
+    Home(HomeScreen),
+    Stats(StatsScreen),
+}
+
+struct HomeScreen {
+    greeting: &'static str,
+    entered: u32,
+}
+
+#[page_impl(AppPagesID)]
+impl HomeScreen {
+    #[init]
+    fn new() -> Self {
+        HomeScreen {
+            greeting: "hello",
+            entered: 0,
+        }
+    }
+
+    #[draw]
+    fn render(&mut self, frame: &mut Frame) {
+        let text = format!("{} {}", self.greeting, self.entered);
+        frame.render_widget(Paragraph::new(text), frame.area());
+    }
+
+    #[on_enter]
+    fn entered(&mut self, _router: Router<AppPagesID>) {
+        self.entered += 1;
+    }
+
+    #[on_event]
+    async fn handle(&mut self, _event: Event, router: Router<AppPagesID>) {
+        tokio::task::yield_now().await;
+        router.push(AppPagesID::Stats);
+    }
+}
+
+struct StatsScreen {
+    label: &'static str,
+}
+
+#[page_impl(AppPagesID, state = Counter)]
+impl StatsScreen {
+    #[init]
+    fn new() -> Self {
+        StatsScreen { label: "count" }
+    }
+
+    #[draw]
+    fn render(&mut self, frame: &mut Frame, state: &Counter) {
+        let text = format!("{} {}", self.label, state.count);
+        frame.render_widget(Paragraph::new(text), frame.area());
+    }
+
+    #[on_enter]
+    async fn entered(&mut self, _router: Router<AppPagesID>, state: &mut Counter) {
+        tokio::task::yield_now().await;
+        state.count += 1;
+    }
+
+    #[on_exit]
+    fn left(&mut self, router: Router<AppPagesID>, _state: &mut Counter) {
+        router.redraw();
+    }
+}
+
+fn render(id: AppPagesID, state: &Counter) -> String {
+    let mut page: AppPages = PageState::<Counter>::new(id);
+
+    let mut terminal = Terminal::new(TestBackend::new(20, 1)).unwrap();
+    terminal
+        .draw(|frame| PageState::<Counter>::draw(&mut page, frame, state))
+        .unwrap();
+
+    terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn main() {
+    let home = HomeScreen::default();
+    assert_eq!((home.greeting, home.entered), ("hello", 0));
+    assert_eq!(StatsScreen::default().label, "count");
+
+    assert_eq!(render(AppPagesID::Home, &Counter::default()), "hello 0");
+    assert_eq!(render(AppPagesID::Stats, &Counter { count: 3 }), "count 3");
+}
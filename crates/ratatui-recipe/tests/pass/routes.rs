@@ -0,0 +1,71 @@
+use ratatui::Frame;
+use ratatui_recipe::{App, Guard, Page, Pages, Routes};
+
+#[derive(Default)]
+struct Session {
+    signed_in: bool,
+}
+
+#[derive(Pages)]
+enum Launcher {
+    #[default]
+    Pick(PickScreen),
+}
+
+#[derive(Pages)]
+enum Main {
+    #[default]
+    Home(HomeScreen),
+    Login(LoginScreen),
+}
+
+#[derive(Default)]
+struct PickScreen;
+
+impl Page<LauncherID> for PickScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<MainID> for HomeScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+#[derive(Default)]
+struct LoginScreen;
+
+impl Page<MainID> for LoginScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+fn require_session(id: MainID, session: &Session) -> Guard<MainID> {
+    match id {
+        MainID::Home if !session.signed_in => Guard::Redirect(MainID::Login),
+        _ => Guard::Allow,
+    }
+}
+
+fn reject_all(_id: MainID, _session: &Session) -> Guard<MainID> {
+    Guard::Cancel
+}
+
+// One app and its state can run a launcher and then the main UI.
+async fn sequential(app: &mut App<Session>) -> std::io::Result<()> {
+    app.run::<Launcher>().await?;
+    app.run_with::<Main>(Routes::new().middleware(require_session))
+        .await
+}
+
+#[tokio::main]
+async fn main() {
+    let _ = sequential;
+
+    let mut app = App::stateful(Session::default());
+    let rejected = app
+        .run_with::<Main>(Routes::new().initial_route(MainID::Home).middleware(reject_all))
+        .await;
+
+    assert!(rejected.is_err());
+}
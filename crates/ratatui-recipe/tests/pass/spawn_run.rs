@@ -0,0 +1,49 @@
+use ratatui::{Frame, crossterm::event::Event};
+use ratatui_recipe::{App, Page, Pages, Router, StatefulPage};
+
+#[derive(Default)]
+struct GlobalState {
+    visits: u32,
+}
+
+#[derive(Pages)]
+enum AppPages {
+    #[default]
+    Home(HomeScreen),
+    Stats(StatsScreen),
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<AppPagesID> for HomeScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+
+    async fn on_event(&mut self, _event: Event, router: Router<AppPagesID>) {
+        tokio::task::yield_now().await;
+        router.push(AppPagesID::Stats);
+    }
+}
+
+#[derive(Default)]
+struct StatsScreen;
+
+impl StatefulPage<AppPagesID, GlobalState> for StatsScreen {
+    fn draw(&mut self, _frame: &mut Frame, _state: &GlobalState) {}
+
+    async fn on_enter(&mut self, _router: Router<AppPagesID>, state: &mut GlobalState) {
+        tokio::task::yield_now().await;
+        state.visits += 1;
+    }
+}
+
+fn spawn() -> tokio::task::JoinHandle<std::io::Result<()>> {
+    tokio::spawn(async {
+        let mut app = App::stateful(GlobalState::default());
+        app.run::<AppPages>().await
+    })
+}
+
+fn main() {
+    let _ = spawn;
+}
@@ -0,0 +1,18 @@
+use ratatui::Frame;
+use ratatui_recipe::{Page, Pages};
+
+#[derive(Pages)]
+enum Pages {
+    #[default]
+    #[page(single_top, single_task)]
+    Home(HomeScreen),
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<PagesID> for HomeScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+fn main() {}
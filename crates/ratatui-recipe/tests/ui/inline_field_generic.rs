@@ -0,0 +1,28 @@
+use std::marker::PhantomData;
+
+use ratatui::Frame;
+use ratatui_recipe::{Page, Pages};
+
+#[derive(Pages)]
+enum Pages<T: Send> {
+    #[default]
+    Home(HomeScreen<T>),
+    #[page(render = detail)]
+    Detail { item: T },
+}
+
+struct HomeScreen<T>(PhantomData<T>);
+
+impl<T> Default for HomeScreen<T> {
+    fn default() -> Self {
+        HomeScreen(PhantomData)
+    }
+}
+
+impl<T: Send> Page<PagesID> for HomeScreen<T> {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+fn detail<T>(_frame: &mut Frame, _item: T) {}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use ratatui::Frame;
+use ratatui_recipe::Pages;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct UserId(u32);
+
+#[derive(Pages)]
+enum Pages {
+    #[default]
+    #[page(render = profile)]
+    Profile { user: UserId },
+    #[page(render = search)]
+    Search { query: String },
+}
+
+fn profile(_frame: &mut Frame, _user: UserId) {}
+
+fn search(_frame: &mut Frame, _query: String) {}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use ratatui::Frame;
+use ratatui_recipe::{Page, Pages};
+
+#[derive(Pages)]
+#[pages(vis = "public")]
+enum Pages {
+    #[default]
+    Home(HomeScreen),
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<PagesID> for HomeScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ratatui::crossterm::event::Event;
+use ratatui_recipe::keys;
+
+fn main() {
+    let event = Event::FocusGained;
+
+    keys!(event, {
+        q+ctrl => {}
+    });
+}
@@ -0,0 +1,22 @@
+use ratatui::Frame;
+use ratatui_recipe::{Page, Pages, Router};
+
+#[derive(Pages)]
+enum Pages {
+    #[default]
+    #[page(on_enter = first, on_enter = second)]
+    Home(HomeScreen),
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<PagesID> for HomeScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+async fn first(_page: &mut HomeScreen, _router: Router<PagesID>, _state: &mut ()) {}
+
+async fn second(_page: &mut HomeScreen, _router: Router<PagesID>, _state: &mut ()) {}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use ratatui::Frame;
+use ratatui_recipe::{Page, Pages};
+
+#[derive(Pages)]
+enum Pages {
+    #[default]
+    Home(HomeScreen),
+    #[page(home)]
+    Settings(HomeScreen),
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<PagesID> for HomeScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use ratatui_recipe::Pages;
+
+#[derive(Pages)]
+#[pages(name = "Route")]
+enum Pages {
+    #[page(transition = "zoom")]
+    Home,
+    #[page(sticky)]
+    Settings(u32, u32),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ratatui_recipe::Pages;
+
+#[derive(Pages)]
+struct Pages {
+    home: u32,
+}
+
+fn main() {}
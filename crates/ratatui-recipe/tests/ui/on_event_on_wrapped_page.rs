@@ -0,0 +1,20 @@
+use ratatui::{Frame, crossterm::event::Event};
+use ratatui_recipe::{Page, Pages, Router};
+
+#[derive(Pages)]
+enum Pages {
+    #[default]
+    #[page(on_event = handle)]
+    Home(HomeScreen),
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+impl Page<PagesID> for HomeScreen {
+    fn draw(&mut self, _frame: &mut Frame) {}
+}
+
+async fn handle(_event: Event, _router: Router<PagesID>) {}
+
+fn main() {}
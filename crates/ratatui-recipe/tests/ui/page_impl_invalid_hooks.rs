@@ -0,0 +1,30 @@
+use ratatui::Frame;
+use ratatui_recipe::page_impl;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Route {
+    Home,
+}
+
+struct HomeScreen;
+
+#[page_impl(Route)]
+impl HomeScreen {
+    #[init]
+    fn new(&self) -> Self {
+        HomeScreen
+    }
+
+    #[draw]
+    async fn render(&mut self, _frame: &mut Frame<'_>) {}
+
+    #[draw]
+    fn render_again(&mut self, _frame: &mut Frame) {}
+
+    #[on_enter]
+    fn entered() {}
+}
+
+fn main() {
+    let _ = Route::Home;
+}
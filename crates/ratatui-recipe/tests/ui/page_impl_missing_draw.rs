@@ -0,0 +1,20 @@
+use ratatui::crossterm::event::Event;
+use ratatui_recipe::{Router, page_impl};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Route {
+    Home,
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+#[page_impl(Route)]
+impl HomeScreen {
+    #[on_event]
+    async fn handle(&mut self, _event: Event, _router: Router<Route>) {}
+}
+
+fn main() {
+    let _ = Route::Home;
+}
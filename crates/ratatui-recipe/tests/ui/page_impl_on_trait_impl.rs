@@ -0,0 +1,20 @@
+use ratatui_recipe::page_impl;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Route {
+    Home,
+}
+
+#[derive(Default)]
+struct HomeScreen;
+
+#[page_impl(Route)]
+impl Clone for HomeScreen {
+    fn clone(&self) -> Self {
+        HomeScreen
+    }
+}
+
+fn main() {
+    let _ = Route::Home;
+}
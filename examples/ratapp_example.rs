@@ -1,11 +1,11 @@
 use ratatui::{
     Frame,
-    crossterm::event::{Event, KeyCode},
+    crossterm::event::Event,
     layout::{Constraint, Layout},
     text::Line,
     widgets::{List, ListItem, ListState, Paragraph},
 };
-use ratatui_recipe::{App, Page, Pages, Router};
+use ratatui_recipe::{App, Page, Pages, Router, keys};
 
 #[tokio::main]
 async fn main() {
@@ -16,22 +16,18 @@ async fn main() {
 
 #[derive(Pages)]
 enum Pages {
+    #[default]
     Home(HomeScreen),
+    #[page(keep_alive, transition = "slide")]
     List(ListScreen),
 }
 
-impl Default for Pages {
-    fn default() -> Self {
-        Pages::Home(HomeScreen::default())
-    }
-}
-
 #[derive(Default)]
 struct HomeScreen {
     counter: u32,
 }
 
-impl Page<pageID> for HomeScreen {
+impl Page<PagesID> for HomeScreen {
     fn draw(&mut self, frame: &mut Frame) {
         let text = Paragraph::new(vec![
             Line::from("Hello ratapp!"),
@@ -48,26 +44,15 @@ impl Page<pageID> for HomeScreen {
         frame.render_widget(text, frame.area());
     }
 
-    async fn on_event(&mut self, event: Event, navigator: Router<pageID>) {
-        if let Event::Key(key_event) = event {
-            match key_event.code {
-                KeyCode::Up => {
-                    self.counter = self.counter.saturating_add(1);
-                }
-                KeyCode::Down => {
-                    self.counter = self.counter.saturating_sub(1);
-                }
-                KeyCode::Enter => {
-                    navigator.push(pageID::List);
-                }
-                KeyCode::Char('q') => {
-                    navigator.exit();
-                }
-                _ => {}
-            }
-
-            navigator.redraw();
-        }
+    async fn on_event(&mut self, event: Event, navigator: Router<PagesID>) {
+        keys!(event, {
+            up => self.counter = self.counter.saturating_add(1),
+            down => self.counter = self.counter.saturating_sub(1),
+            enter => navigator.push(PagesID::List),
+            q | ctrl+c => navigator.exit(),
+        });
+
+        navigator.redraw();
     }
 }
 
@@ -83,7 +68,7 @@ impl Default for ListScreen {
     }
 }
 
-impl Page<pageID> for ListScreen {
+impl Page<PagesID> for ListScreen {
     fn draw(&mut self, frame: &mut Frame) {
         let layout = Layout::vertical([
             Constraint::Length(3),
@@ -112,31 +97,16 @@ impl Page<pageID> for ListScreen {
         frame.render_widget(text, text_area);
     }
 
-    async fn on_event(&mut self, event: Event, navigator: Router<pageID>) {
-        if let Event::Key(key_event) = event {
-            match key_event.code {
-                KeyCode::Up => {
-                    self.state.select_previous();
-                }
-                KeyCode::Down => {
-                    self.state.select_next();
-                }
-                KeyCode::PageUp => {
-                    self.state.select_first();
-                }
-                KeyCode::PageDown => {
-                    self.state.select_last();
-                }
-                KeyCode::Enter => {
-                    navigator.back();
-                }
-                KeyCode::Char('q') => {
-                    navigator.exit();
-                }
-                _ => {}
-            }
-
-            navigator.redraw();
-        }
+    async fn on_event(&mut self, event: Event, navigator: Router<PagesID>) {
+        keys!(event, {
+            up => self.state.select_previous(),
+            down => self.state.select_next(),
+            pageup => self.state.select_first(),
+            pagedown => self.state.select_last(),
+            enter => navigator.back(),
+            q | ctrl+c => navigator.exit(),
+        });
+
+        navigator.redraw();
     }
 }
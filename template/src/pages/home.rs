@@ -1,17 +1,17 @@
 use ratatui::{
     Frame,
-    crossterm::event::{Event, KeyCode},
+    crossterm::event::Event,
     text::Line,
     widgets::{Block, Borders, Paragraph},
 };
-use ratatui_recipe::{Router, StatefulPage};
+use ratatui_recipe::{Router, StatefulPage, keys};
 
-use crate::{GlobalState, pages::pageID};
+use crate::{GlobalState, pages::AppPagesID};
 
 #[derive(Default)]
 pub struct HomeScreen;
 
-impl StatefulPage<pageID, GlobalState> for HomeScreen {
+impl StatefulPage<AppPagesID, GlobalState> for HomeScreen {
     fn draw(&mut self, frame: &mut Frame, _state: &GlobalState) {
         let area = frame.area();
         let widget = Paragraph::new(vec![Line::from("Hello, world!")])
@@ -19,14 +19,16 @@ impl StatefulPage<pageID, GlobalState> for HomeScreen {
         frame.render_widget(widget, area);
     }
 
-    async fn on_event(&mut self, event: Event, router: Router<pageID>, state: &mut GlobalState) {
-        if let Event::Key(key) = event {
-            match key.code {
-                KeyCode::Esc => router.exit(),
-                _ => {}
-            }
+    async fn on_event(
+        &mut self,
+        event: Event,
+        router: Router<AppPagesID>,
+        _state: &mut GlobalState,
+    ) {
+        keys!(event, {
+            esc | ctrl+c => router.exit(),
+        });
 
-            router.redraw()
-        }
+        router.redraw()
     }
 }
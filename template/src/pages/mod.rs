@@ -4,11 +4,6 @@ mod home;
 
 #[derive(Pages)]
 pub enum AppPages {
+    #[default]
     Home(home::HomeScreen),
 }
-
-impl Default for AppPages {
-    fn default() -> Self {
-        AppPages::Home(home::HomeScreen::default())
-    }
-}